@@ -0,0 +1,167 @@
+//! `--annotate`: write each marked item's stable ID into its source
+//! comment, e.g. `// TODO[T:a1b2c3]: Parse flags`, so the ID lives in the
+//! code as well as in TODO.md.
+
+use crate::todo_extractor_internal::aggregator::split_stable_id;
+use crate::{MarkedItem, MarkerConfig};
+use log::info;
+use std::path::Path;
+
+/// Insert `[T:<id>]` right after the marker of every item in `items` (all
+/// from `file`) whose comment has no ID yet, with `id_of` giving the ID.
+/// Only the marker lines are touched, and only by inserting the tag: line
+/// endings and everything else stay byte for byte. A line where the
+/// marker can't be told apart from another marker word is left alone.
+///
+/// Returns whether the file was rewritten.
+pub fn annotate_file(
+    file: &Path,
+    items: &[MarkedItem],
+    config: &MarkerConfig,
+    id_of: impl Fn(&MarkedItem) -> String,
+) -> std::io::Result<bool> {
+    let content = std::fs::read_to_string(file)?;
+    let patterns = config.match_patterns();
+    // (byte offset, tag) per insertion, in line order.
+    let mut insertions = Vec::new();
+    let mut offset = 0;
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let line_number = index + 1;
+        for item in items.iter().filter(|item| item.line_number == line_number) {
+            // An ID kept in the message came from the comment itself.
+            if split_stable_id(&item.message).is_some() {
+                continue;
+            }
+            match tag_position(line, &patterns, &item.message) {
+                Some(position) => {
+                    insertions.push((offset + position, format!("[T:{}]", id_of(item))))
+                }
+                None => info!(
+                    "Not annotating {}:{line_number}: no single untagged marker on the line",
+                    file.display()
+                ),
+            }
+        }
+        offset += line.len();
+    }
+    if insertions.is_empty() {
+        return Ok(false);
+    }
+
+    let mut annotated = String::with_capacity(content.len() + insertions.len() * 10);
+    let mut copied = 0;
+    for (position, tag) in insertions {
+        annotated.push_str(&content[copied..position]);
+        annotated.push_str(&tag);
+        copied = position;
+    }
+    annotated.push_str(&content[copied..]);
+    std::fs::write(file, annotated)?;
+    Ok(true)
+}
+
+/// Byte offset in `line` just after the marker that starts the item with
+/// `message`, or `None` when the line already has a tagged marker or the
+/// marker is ambiguous.
+///
+/// A marker counts when it starts a word and is followed by a space, a
+/// colon or the end of the line. With several such markers on the line
+/// (`# TODO: empty the TODO list`), the one followed by the message's
+/// first word wins.
+fn tag_position(line: &str, patterns: &[String], message: &str) -> Option<usize> {
+    let mut candidates = Vec::new();
+    for pattern in patterns.iter().filter(|p| !p.is_empty()) {
+        for (start, _) in line.match_indices(pattern.as_str()) {
+            let starts_word = line[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+            if !starts_word {
+                continue;
+            }
+            let end = start + pattern.len();
+            let after = &line[end..];
+            if split_stable_id(after).is_some() {
+                return None;
+            }
+            if after.trim_end_matches(['\r', '\n']).is_empty()
+                || after.starts_with([' ', '\t', ':'])
+            {
+                candidates.push((end, after));
+            }
+        }
+    }
+    if candidates.len() > 1 {
+        let first_word = message.split_whitespace().next().unwrap_or_default();
+        candidates.retain(|(_, after)| {
+            after.trim_start_matches(':').split_whitespace().next() == Some(first_word)
+        });
+    }
+    match candidates.as_slice() {
+        [(end, _)] => Some(*end),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    fn item(line_number: usize, message: &str) -> MarkedItem {
+        MarkedItem {
+            file_path: PathBuf::from("lib.rs"),
+            line_number,
+            message: message.to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        }
+    }
+
+    fn config() -> MarkerConfig {
+        MarkerConfig::normalized(vec!["TODO".to_string(), "FIXME".to_string()])
+    }
+
+    #[test]
+    fn test_annotate_inserts_tag_after_marker() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("lib.rs");
+        std::fs::write(
+            &file,
+            "fn main() {}\r\n// TODO: Parse flags\r\nlet s = \"TODO\"; // TODO handle s\r\n// TODO: TODO twice\r\n",
+        )
+        .unwrap();
+        let items = [
+            item(2, "Parse flags"),
+            item(3, "handle s"),
+            item(4, "TODO twice"),
+        ];
+
+        let changed = annotate_file(&file, &items, &config(), |item| {
+            format!("{:06}", item.line_number)
+        })
+        .unwrap();
+
+        assert!(changed);
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "fn main() {}\r\n// TODO[T:000002]: Parse flags\r\nlet s = \"TODO\"; // TODO[T:000003] handle s\r\n// TODO[T:000004]: TODO twice\r\n"
+        );
+    }
+
+    #[test]
+    fn test_annotate_leaves_tagged_lines() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("lib.rs");
+        let content = "// TODO[T:abcdef]: Tagged\n// TODO[T:123456]: Edited since\n";
+        std::fs::write(&file, content).unwrap();
+        // An ID that no longer matches its message is kept in the message.
+        let items = [item(1, "Tagged"), item(2, "[T:123456] Edited since")];
+
+        let changed = annotate_file(&file, &items, &config(), |_| "000000".to_string()).unwrap();
+
+        assert!(!changed);
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), content);
+    }
+}
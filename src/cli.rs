@@ -1,13 +1,27 @@
-use crate::exclusion::{build_exclusion_matcher, filter_excluded_files, ExclusionRule};
+use crate::annotate;
+use crate::exclusion::{
+    build_exclusion_matcher_with_case, explain_excluded_files, filter_excluded_files,
+    ExclusionRule, TEST_PATH_PATTERNS,
+};
 use crate::git_utils::GitOps;
 use crate::git_utils::GitOpsTrait;
 use crate::merge_driver;
-use crate::todo_md;
+use crate::report;
+use crate::todo_extractor_internal::aggregator::{
+    get_effective_extension, parser_by_name, parser_for_file, parser_names, supported_extensions,
+    supported_filenames,
+};
+use crate::todo_md::{self, DedupeBy, WriteOptions};
+use crate::todo_md_internal::TodoCollection;
 use crate::{extract_marked_items_from_file, MarkedItem, MarkerConfig};
+use anstyle::{AnsiColor, Effects, Style};
+use chrono::{DateTime, Utc};
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use git2::Repository;
 use log::{error, info};
-use std::path::{Path, PathBuf};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::{IsTerminal, Write};
+use std::path::{Component, Path, PathBuf};
 
 // ---------------------------------------------------------------------------
 // Public entry points
@@ -65,7 +79,7 @@ pub fn validate_no_empty_todos(new_todos: &[MarkedItem]) -> Result<(), String> {
 // Parsed args + mode dispatch
 // ---------------------------------------------------------------------------
 
-/// What the four mutually-exclusive operating modes do.
+/// What the nine mutually-exclusive operating modes do.
 ///
 /// Each top-level invocation lands in exactly one variant; `Scan` is the
 /// default when no mode-selecting flag is present and is the only mode that
@@ -73,8 +87,13 @@ pub fn validate_no_empty_todos(new_todos: &[MarkedItem]) -> Result<(), String> {
 enum Mode {
     Scan,
     Regenerate,
+    Check,
     Install,
     MergeDriver { ours: PathBuf },
+    List,
+    GitlabReport,
+    RewriteLinks,
+    ListLanguages { json: bool },
 }
 
 /// Everything the CLI needs after parsing. Kept as a flat struct (rather
@@ -83,14 +102,64 @@ enum Mode {
 /// smaller than the cost of variant duplication.
 struct ParsedArgs {
     mode: Mode,
+    /// `--repo-path`: the repository to open, `.` by default.
+    repo_path: PathBuf,
     todo_path: PathBuf,
     marker_config: MarkerConfig,
     exclude_patterns: Vec<String>,
     exclude_dir_patterns: Vec<String>,
     exclusion_rules: Vec<ExclusionRule>,
+    /// `--explain-excludes`: print each excluded file and the pattern that
+    /// excluded it to stderr.
+    explain_excludes: bool,
+    /// `--include`: lowercase extensions to scan; empty means all.
+    include_extensions: Vec<String>,
     files: Vec<PathBuf>,
     auto_add: bool,
     auto_install_merge_driver: bool,
+    write_options: WriteOptions,
+    warn_threshold: Option<usize>,
+    max_todos: Option<usize>,
+    /// `--change-log`: file to append the entries each run added to and
+    /// removed from TODO.md.
+    change_log: Option<PathBuf>,
+    /// `--summary-json`: where to write per-marker and per-file counts of
+    /// what ended up in TODO.md.
+    summary_json: Option<PathBuf>,
+    /// `--since-days`: drop items whose line was last committed before this.
+    since: Option<DateTime<Utc>>,
+    /// `--known-issues`: `(file, line)` locations that already have an
+    /// issue; items there are dropped right after extraction.
+    known_issues: Vec<(PathBuf, usize)>,
+    /// `--dedupe-by`: which items count as duplicates; all but the first
+    /// are dropped right after extraction.
+    dedupe_by: DedupeBy,
+    /// `--close-from-commits`: how many recent commit messages to search
+    /// for `Closes <MARKER> <file>:<line>` references.
+    close_from_commits: Option<usize>,
+    /// `--threads`: worker threads for extraction, at least 1.
+    threads: usize,
+    /// `--report-unsupported`: list scanned files without a parser on stderr.
+    report_unsupported: bool,
+    per_dir: Option<usize>,
+    /// `--merge-strategy refresh-all`: also re-extract every file TODO.md
+    /// already lists, not just the scanned ones.
+    refresh_all: bool,
+    /// `--empty-todos warn-fail`: drop empty TODOs, write TODO.md anyway,
+    /// then fail, instead of failing before anything is written.
+    warn_fail_on_empty: bool,
+    /// `--severity-dir`: markers whose items go to `<DIR>/TODO.md` (next to
+    /// `--todo-path`) instead of `--todo-path` itself.
+    severity_dirs: Vec<(String, PathBuf)>,
+    merge_into: bool,
+    /// `--no-create`: leave a missing TODO.md missing unless there is
+    /// something to write into it.
+    no_create: bool,
+    /// `--annotate`: write each scanned item's stable ID into its comment.
+    annotate: bool,
+    /// Whether `--list` output is styled; resolved once from `--color`,
+    /// `NO_COLOR` and whether stdout is a terminal.
+    color: bool,
 }
 
 impl ParsedArgs {
@@ -105,26 +174,192 @@ impl ParsedArgs {
             .get_many::<String>("markers")
             .map(|vals| vals.cloned().collect())
             .unwrap_or_else(|| vec!["TODO".to_string()]);
-        let marker_config = MarkerConfig::normalized(markers);
+        let mut marker_config = MarkerConfig::normalized(markers);
+        marker_config.continuation_indent =
+            matches.get_one::<usize>("continuation_indent").copied();
+        if marker_config.continuation_indent == Some(0) {
+            return Err("--continuation-indent must be at least 1".to_string());
+        }
+        for spec in matches
+            .get_many::<String>("marker_alias")
+            .into_iter()
+            .flatten()
+        {
+            let (alias, canonical) = spec
+                .split_once('=')
+                .ok_or_else(|| format!("invalid --marker-alias '{spec}': expected ALIAS=MARKER"))?;
+            let normalize = |m: &str| m.trim().trim_end_matches(':').trim().to_string();
+            marker_config
+                .aliases
+                .insert(normalize(alias), normalize(canonical));
+        }
+        for spec in matches
+            .get_many::<String>("parser_override")
+            .into_iter()
+            .flatten()
+        {
+            let (ext, lang) = spec
+                .split_once('=')
+                .ok_or_else(|| format!("invalid --parser-override '{spec}': expected EXT=LANG"))?;
+            let parser = parser_by_name(lang.trim()).ok_or_else(|| {
+                format!(
+                    "invalid --parser-override '{spec}': unknown parser '{}', expected one of: {}",
+                    lang.trim(),
+                    parser_names().join(", ")
+                )
+            })?;
+            let ext = ext.trim().trim_start_matches('.').to_lowercase();
+            marker_config.parser_overrides.insert(ext, parser);
+        }
+        marker_config.require_colon = matches.get_flag("require_colon");
+        marker_config.skip_binary = matches.get_flag("skip_binary");
+        if let Some(separator) = matches.get_one::<String>("multiline_join") {
+            // Every entry is a single line in TODO.md.
+            if separator.contains(['\n', '\r']) {
+                return Err(
+                    "invalid --multiline-join: the separator can't contain a line break"
+                        .to_string(),
+                );
+            }
+            marker_config.multiline_join = separator.clone();
+        }
+        marker_config.scope = matches.get_flag("scope");
+        if let Some(pattern) = matches.get_one::<String>("scope_pattern") {
+            let re = regex::Regex::new(pattern)
+                .map_err(|e| format!("invalid --scope-pattern '{pattern}': {e}"))?;
+            if re.captures_len() < 2 {
+                return Err(format!(
+                    "invalid --scope-pattern '{pattern}': needs a capture group for the name"
+                ));
+            }
+            marker_config.scope_pattern = Some(re);
+        }
 
-        let exclude_patterns: Vec<String> = matches
+        let mut exclude_patterns: Vec<String> = matches
             .get_many::<String>("exclude")
             .map(|vals| vals.cloned().collect())
             .unwrap_or_default();
+        for path in matches
+            .get_many::<String>("exclude_from")
+            .into_iter()
+            .flatten()
+        {
+            exclude_patterns.extend(read_exclude_file(Path::new(path))?);
+        }
+        let mut known_issues = Vec::new();
+        if let Some(path) = matches.get_one::<String>("known_issues") {
+            known_issues = read_known_issues(Path::new(path))?;
+        }
+        if matches.get_flag("ignore_tests") {
+            exclude_patterns.extend(TEST_PATH_PATTERNS.iter().map(|p| p.to_string()));
+        }
         let exclude_dir_patterns: Vec<String> = matches
             .get_many::<String>("exclude_dir")
             .map(|vals| vals.cloned().collect())
             .unwrap_or_default();
-        let exclusion_rules =
-            build_exclusion_matcher(exclude_patterns.clone(), exclude_dir_patterns.clone())
-                .map_err(|e| format!("Error building exclusion patterns: {e}"))?;
+        // Windows filesystems are case-insensitive, so patterns are too.
+        let ignore_case_paths = matches.get_flag("ignore_case_paths") || cfg!(windows);
+        let exclusion_rules = build_exclusion_matcher_with_case(
+            exclude_patterns.clone(),
+            exclude_dir_patterns.clone(),
+            ignore_case_paths,
+        )
+        .map_err(|e| format!("Error building exclusion patterns: {e}"))?;
 
-        let files: Vec<PathBuf> = matches
+        let include_extensions: Vec<String> = matches
+            .get_many::<String>("include")
+            .map(|vals| {
+                vals.map(|ext| ext.trim_start_matches('.').to_lowercase())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut files: Vec<PathBuf> = matches
             .get_many::<String>("files")
             .map(|vals| vals.map(PathBuf::from).collect())
             .unwrap_or_default();
+        if matches.get_flag("normalize_paths") {
+            files = files.iter().map(|file| normalize_path(file)).collect();
+        }
 
-        let mode = if let Some(vals) = matches.get_many::<String>("merge_driver") {
+        let preamble = match matches.get_one::<String>("preamble_file") {
+            Some(path) => Some(
+                std::fs::read_to_string(path)
+                    .map_err(|e| format!("failed to read --preamble-file {path}: {e}"))?,
+            ),
+            None => matches.get_one::<String>("preamble").cloned(),
+        };
+
+        // Links are relative to the directory of `--todo-path`, not the
+        // file git hands the merge driver (which is a temp file).
+        let write_options = WriteOptions {
+            link_base: matches
+                .get_flag("relative_to_todo")
+                .then(|| todo_path.parent().unwrap_or(Path::new("")).to_path_buf()),
+            dedup_global: matches.get_flag("dedup_global"),
+            summary_header: matches.get_flag("summary_header"),
+            group_by_assignee: matches
+                .get_one::<String>("group_by")
+                .is_some_and(|g| g == "assignee"),
+            timestamp: matches.get_flag("timestamp"),
+            keep_reviewed: matches.get_flag("keep_reviewed"),
+            line_anchor: matches.get_one::<String>("line_anchor").cloned(),
+            no_links: matches.get_flag("no_links"),
+            sort_files_by_count: matches
+                .get_one::<String>("sort_files")
+                .is_some_and(|s| s == "count"),
+            flat: matches.get_flag("flat"),
+            no_sort: matches.get_flag("no_sort"),
+            preamble,
+            uppercase_markers: matches.get_flag("uppercase_markers"),
+            // The IDs `--annotate` writes into comments are the ones TODO.md shows.
+            stable_ids: matches.get_flag("stable_ids") || matches.get_flag("annotate"),
+            // Filled per scan from the commit history.
+            closed: Vec::new(),
+            project_roots: matches
+                .get_many::<String>("project_roots")
+                .map(|vals| vals.map(PathBuf::from).collect())
+                .unwrap_or_default(),
+        };
+        if let Some(anchor) = &write_options.line_anchor {
+            if !anchor.contains("{line}") {
+                return Err(format!(
+                    "--line-anchor '{anchor}' must contain the {{line}} placeholder"
+                ));
+            }
+        }
+
+        let threads = match matches.get_one::<usize>("threads") {
+            Some(0) => return Err("--threads must be at least 1".to_string()),
+            Some(n) => *n,
+            None => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        };
+
+        let per_dir = matches.get_one::<usize>("per_dir").copied();
+        if per_dir == Some(0) {
+            return Err("--per-dir depth must be at least 1".to_string());
+        }
+        let mut severity_dirs = Vec::new();
+        for spec in matches
+            .get_many::<String>("severity_dir")
+            .into_iter()
+            .flatten()
+        {
+            let (marker, dir) = spec
+                .split_once('=')
+                .filter(|(marker, dir)| !marker.trim().is_empty() && !dir.trim().is_empty())
+                .ok_or_else(|| format!("invalid --severity-dir '{spec}': expected MARKER=DIR"))?;
+            severity_dirs.push((
+                marker.trim().trim_end_matches(':').trim().to_string(),
+                PathBuf::from(dir.trim()),
+            ));
+        }
+
+        let mode = if let Some(format) = matches.get_one::<String>("list_languages") {
+            Mode::ListLanguages {
+                json: format == "json",
+            }
+        } else if let Some(vals) = matches.get_many::<String>("merge_driver") {
             // git passes %O %A %B; OURS is the second value and the only one
             // the driver writes to.
             let triple: Vec<&String> = vals.collect();
@@ -132,35 +367,93 @@ impl ParsedArgs {
             Mode::MergeDriver { ours }
         } else if matches.get_flag("regenerate") {
             Mode::Regenerate
+        } else if matches.get_flag("check") {
+            Mode::Check
         } else if matches.get_flag("install_merge_driver") {
             Mode::Install
+        } else if matches.get_flag("rewrite_links") {
+            Mode::RewriteLinks
+        } else if matches.get_flag("list") {
+            Mode::List
+        } else if matches.get_one::<String>("format").map(String::as_str) == Some("gitlab") {
+            Mode::GitlabReport
         } else {
             Mode::Scan
         };
 
         Ok(ParsedArgs {
             mode,
+            repo_path: PathBuf::from(
+                matches
+                    .get_one::<String>("repo_path")
+                    .expect("--repo-path has a default value"),
+            ),
             todo_path,
             marker_config,
             exclude_patterns,
             exclude_dir_patterns,
             exclusion_rules,
+            explain_excludes: matches.get_flag("explain_excludes"),
+            include_extensions,
             files,
             auto_add: matches.get_flag("auto_add"),
             auto_install_merge_driver: matches.get_flag("auto_install_merge_driver"),
+            write_options,
+            warn_threshold: matches.get_one::<usize>("warn_threshold").copied(),
+            since: matches
+                .get_one::<u32>("since_days")
+                .map(|days| Utc::now() - chrono::Duration::days(i64::from(*days))),
+            max_todos: matches.get_one::<usize>("max_todos").copied(),
+            summary_json: matches.get_one::<String>("summary_json").map(PathBuf::from),
+            change_log: matches.get_one::<String>("change_log").map(PathBuf::from),
+            known_issues,
+            dedupe_by: match matches.get_one::<String>("dedupe_by").map(String::as_str) {
+                Some("message") => DedupeBy::Message,
+                Some("line") => DedupeBy::Line,
+                Some("full") => DedupeBy::Full,
+                _ => DedupeBy::None,
+            },
+            close_from_commits: matches.get_one::<usize>("close_from_commits").copied(),
+            threads,
+            report_unsupported: matches.get_flag("report_unsupported"),
+            per_dir,
+            refresh_all: matches
+                .get_one::<String>("merge_strategy")
+                .is_some_and(|s| s == "refresh-all"),
+            warn_fail_on_empty: matches
+                .get_one::<String>("empty_todos")
+                .is_some_and(|s| s == "warn-fail"),
+            severity_dirs,
+            merge_into: matches.get_flag("merge_into"),
+            no_create: matches.get_flag("no_create"),
+            annotate: matches.get_flag("annotate"),
+            color: use_color(
+                matches
+                    .get_one::<String>("color")
+                    .expect("--color has a default value"),
+                std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+                std::io::stdout().is_terminal(),
+            ),
         })
     }
 }
 
 fn dispatch(args: &ParsedArgs, git_ops: &dyn GitOpsTrait) -> Result<(), String> {
-    let repo = git_ops
-        .open_repository(Path::new("."))
-        .map_err(|e| format!("Error opening repository: {e}"))?;
+    let open_repo = || {
+        git_ops
+            .open_repository(&args.repo_path)
+            .map_err(|e| format!("Error opening repository: {e}"))
+    };
     match &args.mode {
-        Mode::MergeDriver { ours } => mode::merge_driver(args, &repo, git_ops, ours),
-        Mode::Regenerate => mode::regenerate(args, &repo, git_ops),
-        Mode::Install => mode::install(args, &repo),
-        Mode::Scan => mode::scan(args, repo, git_ops),
+        Mode::MergeDriver { ours } => mode::merge_driver(args, &open_repo()?, git_ops, ours),
+        Mode::Regenerate => mode::regenerate(args, &open_repo()?, git_ops),
+        Mode::Check => mode::check(args, &open_repo()?, git_ops),
+        Mode::Install => mode::install(args, &open_repo()?),
+        Mode::Scan => mode::scan(args, open_repo()?, git_ops),
+        Mode::List => mode::list(args, &open_repo()?, git_ops),
+        Mode::GitlabReport => mode::gitlab_report(args, &open_repo()?, git_ops),
+        Mode::RewriteLinks => mode::rewrite_links(args),
+        Mode::ListLanguages { json } => mode::list_languages(*json),
     }
 }
 
@@ -179,7 +472,9 @@ mod mode {
         repo: Repository,
         git_ops: &dyn GitOpsTrait,
     ) -> Result<(), String> {
-        ensure_todo_path_exists(&args.todo_path)?;
+        if !args.no_create {
+            ensure_todo_path_exists(&args.todo_path)?;
+        }
         if args.auto_install_merge_driver {
             maybe_auto_install(args, &repo);
         }
@@ -193,10 +488,48 @@ mod mode {
         repo: &Repository,
         git_ops: &dyn GitOpsTrait,
     ) -> Result<(), String> {
-        ensure_todo_path_exists(&args.todo_path)?;
-        regenerate_todo_md(args, repo, git_ops, &args.todo_path, true)?;
+        if !args.no_create {
+            ensure_todo_path_exists(&args.todo_path)?;
+        }
+        let entries_before = change_log_entries(args, &args.todo_path);
+        let total = regenerate_todo_md(args, repo, git_ops, &args.todo_path, true)?;
         info!("TODO.md successfully regenerated.");
-        Ok(())
+        append_change_log(args, &args.todo_path, entries_before)?;
+        if args.summary_json.is_some() {
+            let written = todo_md::read_todo_file(&args.todo_path)
+                .map_err(|e| format!("failed to read {}: {e}", args.todo_path.display()))?;
+            write_summary_json(args, &written)?;
+        }
+        check_todo_limit(total, args.max_todos)
+    }
+
+    /// `--check`: CI gate. Render what `--regenerate` would write and
+    /// compare it byte-for-byte with the existing TODO.md, printing a line
+    /// diff and failing when they differ. Never writes TODO.md.
+    pub(super) fn check(
+        args: &ParsedArgs,
+        repo: &Repository,
+        git_ops: &dyn GitOpsTrait,
+    ) -> Result<(), String> {
+        let todos = scan_tracked_todos(args, repo, git_ops, true)?;
+        let expected = todo_md::render_todo_file(todos, &args.write_options);
+        let actual = match std::fs::read_to_string(&args.todo_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("failed to read {}: {e}", args.todo_path.display())),
+        };
+        // A `--timestamp` line always differs, so it is left out.
+        let actual = todo_md::without_timestamp(&actual);
+        let expected = todo_md::without_timestamp(&expected);
+        if actual == expected {
+            info!("TODO.md is up to date.");
+            return Ok(());
+        }
+        eprint!("{}", format_line_diff(&args.todo_path, actual, expected));
+        Err(format!(
+            "{} is out of date; run `rusty-todo-md --regenerate` to update it",
+            args.todo_path.display()
+        ))
     }
 
     /// `--install-merge-driver`: register the driver in `.git/config` and
@@ -215,6 +548,103 @@ mod mode {
         Ok(())
     }
 
+    /// `--list`: print the marked items to stdout, grouped by marker.
+    /// Scans the given files, or every tracked file when none are given.
+    /// Never writes TODO.md.
+    pub(super) fn list(
+        args: &ParsedArgs,
+        repo: &Repository,
+        git_ops: &dyn GitOpsTrait,
+    ) -> Result<(), String> {
+        let todos = scan_given_or_tracked(args, repo, git_ops)?;
+        print!("{}", format_todo_list(todos, args.color));
+        Ok(())
+    }
+
+    /// `--format gitlab`: print a GitLab Code Quality report to stdout.
+    /// Scans like `--list` and never writes TODO.md.
+    pub(super) fn gitlab_report(
+        args: &ParsedArgs,
+        repo: &Repository,
+        git_ops: &dyn GitOpsTrait,
+    ) -> Result<(), String> {
+        let todos = scan_given_or_tracked(args, repo, git_ops)?;
+        println!("{}", report::gitlab_code_quality(&todos));
+        Ok(())
+    }
+
+    /// `--rewrite-links`: re-extract the files TODO.md references and fix
+    /// the line numbers of entries whose comment moved, in place. Entries
+    /// that no longer match anything are left alone.
+    pub(super) fn rewrite_links(args: &ParsedArgs) -> Result<(), String> {
+        let entries = todo_md::read_todo_file(&args.todo_path)
+            .map_err(|e| format!("failed to read {}: {e}", args.todo_path.display()))?;
+        let files: BTreeSet<PathBuf> = entries
+            .into_iter()
+            .map(|item| item.file_path)
+            .filter(|file| file.exists())
+            .collect();
+        let files: Vec<PathBuf> = files.into_iter().collect();
+        let current = extract_todos_from_files(&files, args);
+        let moved = todo_md::rewrite_line_numbers(&args.todo_path, &current)
+            .map_err(|e| format!("failed to update {}: {e}", args.todo_path.display()))?;
+        info!(
+            "Updated {moved} line number(s) in {}",
+            args.todo_path.display()
+        );
+        Ok(())
+    }
+
+    /// The marked items in the given files, or in every tracked file when
+    /// none are given, after exclusions.
+    fn scan_given_or_tracked(
+        args: &ParsedArgs,
+        repo: &Repository,
+        git_ops: &dyn GitOpsTrait,
+    ) -> Result<Vec<MarkedItem>, String> {
+        let files = if args.files.is_empty() {
+            git_ops
+                .get_tracked_files(repo)
+                .map_err(|e| format!("failed to enumerate tracked files: {e}"))?
+        } else {
+            args.files.clone()
+        };
+        let filtered = select_files(files, args);
+        let mut todos = extract_todos_from_files(&filtered, args);
+        retain_recent(&mut todos, args, repo, git_ops);
+        Ok(todos)
+    }
+
+    /// `--list-languages`: print every supported extension and special file
+    /// name, for tooling that needs to build `files:` filters. Works outside
+    /// a git repository.
+    pub(super) fn list_languages(json: bool) -> Result<(), String> {
+        let extensions = supported_extensions();
+        let filenames = supported_filenames();
+        if json {
+            let quote = |names: &[&str]| {
+                names
+                    .iter()
+                    .map(|n| format!("\"{n}\""))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            };
+            println!(
+                "{{\"extensions\":[{}],\"filenames\":[{}]}}",
+                quote(&extensions),
+                quote(&filenames)
+            );
+        } else {
+            let lines: String = extensions
+                .iter()
+                .chain(filenames.iter())
+                .map(|name| format!("{name}\n"))
+                .collect();
+            print!("{lines}");
+        }
+        Ok(())
+    }
+
     /// Git merge-driver entry point. Ignores BASE/THEIRS — at invocation
     /// time the working tree's source files already reflect the cumulative
     /// state of all replayed commits (for files that didn't themselves
@@ -270,22 +700,199 @@ mod mode {
 // Shared helpers (used by multiple modes)
 // ---------------------------------------------------------------------------
 
-fn extract_todos_from_files(files: &[PathBuf], marker_config: &MarkerConfig) -> Vec<MarkedItem> {
-    let mut new_todos = Vec::new();
-    for file in files {
-        match extract_marked_items_from_file(file, marker_config) {
-            Ok(mut todos) => new_todos.append(&mut todos),
-            Err(e) => error!("Error processing file {:?}: {}", file, e),
+/// Extract the marked items of `files` on up to `--threads` worker threads,
+/// minus the `--known-issues`. Results keep the order of `files`, whatever
+/// the thread count.
+fn extract_todos_from_files(files: &[PathBuf], args: &ParsedArgs) -> Vec<MarkedItem> {
+    let marker_config = &args.marker_config;
+    let threads = args.threads;
+    let mut seen = HashSet::new();
+    let unique: Vec<&PathBuf> = files
+        .iter()
+        .filter(|file| {
+            // A symlink and its target resolve to the same file; scanning
+            // both would list every TODO twice under different paths. Keep
+            // the path we were given first.
+            let Ok(canonical) = std::fs::canonicalize(file) else {
+                return true;
+            };
+            let first = seen.insert(canonical);
+            if !first {
+                info!("Skipping {file:?}: same file as an earlier path");
+            }
+            first
+        })
+        .collect();
+    if args.report_unsupported {
+        report_unsupported_files(&unique, marker_config);
+    }
+
+    let extract = |chunk: &[&PathBuf]| {
+        let mut todos = Vec::new();
+        for file in chunk {
+            match extract_marked_items_from_file(file, marker_config) {
+                Ok(mut items) => todos.append(&mut items),
+                Err(e) => error!("Error processing file {:?}: {}", file, e),
+            }
+        }
+        todos
+    };
+    let mut todos = if threads <= 1 || unique.len() <= 1 {
+        extract(&unique)
+    } else {
+        let chunk_size = unique.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = unique
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || extract(chunk)))
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("extraction thread panicked"))
+                .collect()
+        })
+    };
+    // `--known-issues`: already ticketed elsewhere.
+    todos.retain(|item| {
+        !args
+            .known_issues
+            .iter()
+            .any(|(file, line)| item.is_at(file, *line))
+    });
+    todo_md::dedupe_items(todos, args.dedupe_by)
+}
+
+/// `--report-unsupported`: list the files no parser handles on stderr, so a
+/// missing TODO can be told apart from an unsupported file type.
+fn report_unsupported_files(files: &[&PathBuf], marker_config: &MarkerConfig) {
+    let unsupported: Vec<&&PathBuf> = files
+        .iter()
+        .filter(|file| parser_for_file(file, marker_config).is_none())
+        .collect();
+    if unsupported.is_empty() {
+        return;
+    }
+    eprintln!(
+        "rusty-todo-md: {} file(s) skipped, no parser for their type:",
+        unsupported.len()
+    );
+    for file in unsupported {
+        eprintln!("  {}", file.display());
+    }
+}
+
+/// `--exclude-from`: glob patterns, one per line. Blank lines and lines
+/// starting with `#` are skipped.
+fn read_exclude_file(path: &Path) -> Result<Vec<String>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read --exclude-from {}: {e}", path.display()))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+/// `--known-issues`: one `<file>:<line>` reference per line. Blank lines
+/// and lines starting with `#` are skipped.
+fn read_known_issues(path: &Path) -> Result<Vec<(PathBuf, usize)>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read --known-issues {}: {e}", path.display()))?;
+    content
+        .lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            line.rsplit_once(':')
+                .and_then(|(file, number)| Some((PathBuf::from(file), number.parse().ok()?)))
+                .ok_or_else(|| {
+                    format!(
+                        "{}:{}: expected <file>:<line>, got '{line}'",
+                        path.display(),
+                        i + 1
+                    )
+                })
+        })
+        .collect()
+}
+
+/// `--warn-threshold`: flag files that carry more than `threshold` items.
+/// Advisory only — printed with `eprintln!` so it shows without `RUST_LOG`,
+/// and never affects the exit code.
+fn warn_on_todo_density(todos: &[MarkedItem], threshold: Option<usize>) {
+    let Some(threshold) = threshold else {
+        return;
+    };
+    let mut collection = TodoCollection::new();
+    for item in todos {
+        collection.add_item(item.clone());
+    }
+    for (file, count) in collection.files_exceeding(threshold) {
+        eprintln!(
+            "rusty-todo-md: warning: {} has {count} marked items (threshold: {threshold})",
+            file.display()
+        );
+    }
+}
+
+/// Decide whether `--list` output gets ANSI styling. An explicit
+/// `always`/`never` wins; `auto` honors `NO_COLOR` (https://no-color.org)
+/// and only colors a terminal.
+fn use_color(choice: &str, no_color: bool, is_terminal: bool) -> bool {
+    match choice {
+        "always" => true,
+        "never" => false,
+        _ => !no_color && is_terminal,
+    }
+}
+
+/// Human-readable `--list` report: one header per marker with its count,
+/// then one `file:line: message` line per item, sorted.
+fn format_todo_list(todos: Vec<MarkedItem>, color: bool) -> String {
+    let (header, location) = if color {
+        (
+            AnsiColor::Yellow.on_default().effects(Effects::BOLD),
+            AnsiColor::Cyan.on_default(),
+        )
+    } else {
+        (Style::new(), Style::new())
+    };
+
+    let mut by_marker: BTreeMap<String, Vec<MarkedItem>> = BTreeMap::new();
+    for item in todos {
+        by_marker.entry(item.marker.clone()).or_default().push(item);
+    }
+
+    let mut out = String::new();
+    for (marker, mut items) in by_marker {
+        items.sort_by(|a, b| (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number)));
+        out.push_str(&format!("{header}{marker}{header:#} ({})\n", items.len()));
+        for item in items {
+            out.push_str(&format!(
+                "  {location}{}:{}{location:#}: {}\n",
+                item.file_path.display(),
+                item.line_number,
+                item.message
+            ));
         }
     }
-    new_todos
+    out
 }
 
 fn ensure_todo_path_exists(todo_path: &Path) -> Result<(), String> {
     if todo_path.exists() {
         return Ok(());
     }
-    std::fs::write(todo_path, "").map_err(|e| format!("Error creating TODO.md: {e}"))
+    // Create without truncating: a concurrent `--merge-into` run may have
+    // written the file since the check above.
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(todo_path)
+        .map(|_| ())
+        .map_err(|e| format!("Error creating TODO.md: {e}"))
 }
 
 fn warn_if_todo_md_has_conflict_markers(todo_path: &Path) {
@@ -298,6 +905,179 @@ fn warn_if_todo_md_has_conflict_markers(todo_path: &Path) {
     }
 }
 
+/// Write the `--summary-json` file for `todos`, when one was asked for.
+fn write_summary_json(args: &ParsedArgs, todos: &[MarkedItem]) -> Result<(), String> {
+    let Some(path) = &args.summary_json else {
+        return Ok(());
+    };
+    std::fs::write(path, report::summary_json(todos))
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))
+}
+
+/// `--max-todos`: fail (after TODO.md was written) when it holds more than
+/// `max` items, so CI can cap TODO debt.
+fn check_todo_limit(total: usize, max: Option<usize>) -> Result<(), String> {
+    match max {
+        Some(max) if total > max => Err(format!(
+            "TODO.md holds {total} marked items, more than the --max-todos limit of {max}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Unified-style line diff from `actual` (the file on disk) to `expected`
+/// for `--check`. Unchanged lines are omitted; TODO.md is small enough
+/// that a plain LCS table is fine.
+fn format_line_diff(todo_path: &Path, actual: &str, expected: &str) -> String {
+    let old: Vec<&str> = actual.lines().collect();
+    let new: Vec<&str> = expected.lines().collect();
+    // lcs[i][j]: length of the longest common subsequence of old[i..], new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let path = todo_path.display();
+    let mut out = format!("--- {path} (current)\n+++ {path} (expected)\n");
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            out.push_str(&format!("+{}\n", new[j]));
+            j += 1;
+        } else {
+            out.push_str(&format!("-{}\n", old[i]));
+            i += 1;
+        }
+    }
+    if old == new {
+        // Only line endings or the trailing newline differ.
+        out.push_str("(line endings or trailing newline differ)\n");
+    }
+    out
+}
+
+/// `--since-days`: keep only the items whose line was last committed on or
+/// after `args.since`, per `git blame`. Lines not committed yet are new, so
+/// they are kept, as are items that can't be blamed.
+fn retain_recent(
+    todos: &mut Vec<MarkedItem>,
+    args: &ParsedArgs,
+    repo: &Repository,
+    git_ops: &dyn GitOpsTrait,
+) {
+    let Some(since) = args.since else {
+        return;
+    };
+    todos.retain(
+        |item| match git_ops.blame_line(repo, &item.file_path, item.line_number) {
+            Ok(Some(date)) => date >= since,
+            Ok(None) => true,
+            Err(e) => {
+                info!(
+                    "Keeping {}:{}: blame failed: {e}",
+                    item.file_path.display(),
+                    item.line_number
+                );
+                true
+            }
+        },
+    );
+}
+
+/// `--close-from-commits`: the `(marker, file, line)` items referenced as
+/// `Closes <MARKER> <file>:<line>` (case-insensitive) in the last N commit
+/// messages. Failing to read the history only skips closing.
+fn closed_by_commits(
+    args: &ParsedArgs,
+    repo: &Repository,
+    git_ops: &dyn GitOpsTrait,
+) -> Vec<(String, PathBuf, usize)> {
+    let Some(count) = args.close_from_commits else {
+        return Vec::new();
+    };
+    let messages = match git_ops.recent_commit_messages(repo, count) {
+        Ok(messages) => messages,
+        Err(e) => {
+            info!("Not closing TODOs from commit messages: {e}");
+            return Vec::new();
+        }
+    };
+    let re = regex::Regex::new(r"(?i)\bcloses\s+(\w+)\s+(\S+):(\d+)").unwrap();
+    messages
+        .iter()
+        .flat_map(|message| re.captures_iter(message))
+        .filter_map(|caps| {
+            Some((
+                caps[1].to_string(),
+                PathBuf::from(&caps[2]),
+                caps[3].parse().ok()?,
+            ))
+        })
+        .collect()
+}
+
+/// `--normalize-paths`: `path` without `.` components and redundant
+/// separators, e.g. `./src//./main.rs` becomes `src/main.rs`. Purely
+/// lexical: `..` is kept and symlinks aren't resolved.
+fn normalize_path(path: &Path) -> PathBuf {
+    let normalized: PathBuf = path
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect();
+    if normalized.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        normalized
+    }
+}
+
+/// The files to scan: `files` minus `--exclude` matches, restricted to the
+/// `--include` extensions when any are given.
+fn select_files(files: Vec<PathBuf>, args: &ParsedArgs) -> Vec<PathBuf> {
+    if args.explain_excludes {
+        for (file, pattern) in explain_excluded_files(&files, &args.exclusion_rules) {
+            eprintln!("Excluding {} (matched {pattern})", file.display());
+        }
+    }
+    let mut selected = filter_excluded_files(files, &args.exclusion_rules);
+    if !args.include_extensions.is_empty() {
+        selected.retain(|file| {
+            args.include_extensions
+                .contains(&get_effective_extension(file))
+        });
+    }
+    selected
+}
+
+/// Scan every tracked, non-excluded file, as `--regenerate` does.
+fn scan_tracked_todos(
+    args: &ParsedArgs,
+    repo: &Repository,
+    git_ops: &dyn GitOpsTrait,
+    validate_empty: bool,
+) -> Result<Vec<MarkedItem>, String> {
+    let all_files = git_ops
+        .get_tracked_files(repo)
+        .map_err(|e| format!("failed to enumerate tracked files: {e}"))?;
+    let filtered = select_files(all_files, args);
+    let mut todos = extract_todos_from_files(&filtered, args);
+    retain_recent(&mut todos, args, repo, git_ops);
+    warn_on_todo_density(&todos, args.warn_threshold);
+    if validate_empty {
+        validate_no_empty_todos(&todos)?;
+    }
+    Ok(todos)
+}
+
 /// Re-scan the current index and rewrite TODO.md from scratch.
 ///
 /// Shared by the `--regenerate` user command and the `--merge-driver` git
@@ -309,18 +1089,16 @@ fn regenerate_todo_md(
     git_ops: &dyn GitOpsTrait,
     output_path: &Path,
     validate_empty: bool,
-) -> Result<(), String> {
-    let all_files = git_ops
-        .get_tracked_files(repo)
-        .map_err(|e| format!("failed to enumerate tracked files: {e}"))?;
-    let filtered = filter_excluded_files(all_files, &args.exclusion_rules);
-    let todos = extract_todos_from_files(&filtered, &args.marker_config);
-    if validate_empty {
-        validate_no_empty_todos(&todos)?;
+) -> Result<usize, String> {
+    let todos = scan_tracked_todos(args, repo, git_ops, validate_empty)?;
+    if args.no_create && todos.is_empty() && !output_path.exists() {
+        info!("No TODOs found and --no-create set, not creating {output_path:?}");
+        return Ok(0);
     }
-    todo_md::write_todo_file(output_path, todos)
+    let total = todos.len();
+    todo_md::write_todo_file_with_options(output_path, todos, &args.write_options)
         .map_err(|e| format!("failed to write {}: {e}", output_path.display()))?;
-    Ok(())
+    Ok(total)
 }
 
 fn process_files(
@@ -328,30 +1106,415 @@ fn process_files(
     repo: Repository,
     git_ops: &dyn GitOpsTrait,
 ) -> Result<(), String> {
-    let filtered_files = filter_excluded_files(args.files.clone(), &args.exclusion_rules);
-    let new_todos = extract_todos_from_files(&filtered_files, &args.marker_config);
-    let todo_content_before = std::fs::read_to_string(&args.todo_path).ok();
+    let mut files = args.files.clone();
+    if args.refresh_all {
+        files.extend(referenced_files(&args.todo_path, &files)?);
+    }
+    let filtered_files = select_files(files, args);
+    let mut new_todos = extract_todos_from_files(&filtered_files, args);
+    retain_recent(&mut new_todos, args, &repo, git_ops);
 
-    validate_no_empty_todos(&new_todos)?;
+    // `--empty-todos warn-fail` reports the empty TODOs only once the rest
+    // has been written.
+    let empty_check = validate_no_empty_todos(&new_todos);
+    if args.warn_fail_on_empty {
+        new_todos.retain(|item| !item.message.trim().is_empty());
+    } else {
+        empty_check.clone()?;
+    }
+    warn_on_todo_density(&new_todos, args.warn_threshold);
+    if args.annotate {
+        annotate_sources(args, &new_todos, &repo, git_ops)?;
+    }
 
-    if let Err(err) = todo_md::sync_todo_file(&args.todo_path, new_todos, filtered_files) {
-        info!("There was an error updating TODO.md: {err}");
-        sync_fallback_full_rescan(args, &repo, git_ops);
+    let workdir = repo.workdir().map(Path::to_path_buf);
+    let targets = split_by_severity(
+        args,
+        partition_targets(args, workdir.as_deref(), new_todos, filtered_files),
+    );
+    let todo_paths: Vec<PathBuf> = targets.iter().map(|t| t.todo_path.clone()).collect();
+    let closed = closed_by_commits(args, &repo, git_ops);
+    for target in targets {
+        sync_target(args, &repo, git_ops, target, &closed)?;
     }
     info!("TODO.md successfully updated.");
 
+    if args.max_todos.is_some() || args.summary_json.is_some() {
+        // Count what was written, not just this scan: TODO.md also holds
+        // the entries of files that weren't passed this time.
+        let mut written = Vec::new();
+        for todo_path in &todo_paths {
+            written.extend(
+                todo_md::read_todo_file(todo_path)
+                    .map_err(|e| format!("failed to read {}: {e}", todo_path.display()))?,
+            );
+        }
+        write_summary_json(args, &written)?;
+        check_todo_limit(written.len(), args.max_todos)?;
+    }
+    empty_check
+}
+
+/// `--annotate`: write the stable ID of each of `todos` into its comment.
+/// Like TODO.md, rewritten files are only staged again under `--auto-add`;
+/// otherwise they are left modified in the working tree.
+fn annotate_sources(
+    args: &ParsedArgs,
+    todos: &[MarkedItem],
+    repo: &Repository,
+    git_ops: &dyn GitOpsTrait,
+) -> Result<(), String> {
+    let mut by_file: BTreeMap<&Path, Vec<MarkedItem>> = BTreeMap::new();
+    for item in todos {
+        by_file
+            .entry(&item.file_path)
+            .or_default()
+            .push(item.clone());
+    }
+    // The ID must match the one TODO.md shows, which is hashed after
+    // `--uppercase-markers` is applied.
+    let id_of = |item: &MarkedItem| {
+        if args.write_options.uppercase_markers {
+            MarkedItem {
+                marker: item.marker.to_uppercase(),
+                ..item.clone()
+            }
+            .stable_id()
+        } else {
+            item.stable_id()
+        }
+    };
+    for (file, items) in by_file {
+        let changed = annotate::annotate_file(file, &items, &args.marker_config, id_of)
+            .map_err(|e| format!("failed to annotate {}: {e}", file.display()))?;
+        if changed {
+            info!("Annotated {file:?} with stable IDs");
+            if args.auto_add {
+                stage_file(file, repo, git_ops)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `--merge-strategy refresh-all`: the files TODO.md lists that still exist
+/// and aren't in `scanned` already, so their entries are re-extracted too.
+fn referenced_files(todo_path: &Path, scanned: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let entries = todo_md::read_todo_file(todo_path)
+        .map_err(|e| format!("failed to read {}: {e}", todo_path.display()))?;
+    let referenced: BTreeSet<PathBuf> = entries
+        .into_iter()
+        .map(|item| item.file_path)
+        .filter(|file| file.exists() && !scanned.contains(file))
+        .collect();
+    Ok(referenced.into_iter().collect())
+}
+
+/// One TODO.md written by a scan, together with the part of the scan it
+/// owns. Without `--per-dir` and `--severity-dir` there is a single target:
+/// `--todo-path`, owning every scanned file.
+struct TodoTarget {
+    todo_path: PathBuf,
+    /// Partition directory, or `None` for the root `--todo-path`.
+    dir: Option<PathBuf>,
+    /// `--severity-dir` directory whose markers this target collects, or
+    /// `None` for the unmapped markers.
+    severity_dir: Option<PathBuf>,
+    todos: Vec<MarkedItem>,
+    scanned_files: Vec<PathBuf>,
+}
+
+impl TodoTarget {
+    /// Whether this is the `--todo-path` the user asked for, rather than
+    /// one derived from it.
+    fn is_root(&self) -> bool {
+        self.dir.is_none() && self.severity_dir.is_none()
+    }
+
+    /// `--relative-to-todo` links point from the target's own TODO.md.
+    fn write_options(&self, args: &ParsedArgs) -> WriteOptions {
+        let mut options = args.write_options.clone();
+        if !self.is_root() && options.link_base.is_some() {
+            options.link_base = Some(
+                self.todo_path
+                    .parent()
+                    .unwrap_or(Path::new(""))
+                    .to_path_buf(),
+            );
+        }
+        options
+    }
+}
+
+/// Split a scan into [`TodoTarget`]s according to `--per-dir`.
+///
+/// The root target is always first and is handed *every* scanned file, so
+/// entries that moved into a partition are dropped from the root TODO.md
+/// instead of lingering there.
+fn partition_targets(
+    args: &ParsedArgs,
+    workdir: Option<&Path>,
+    todos: Vec<MarkedItem>,
+    files: Vec<PathBuf>,
+) -> Vec<TodoTarget> {
+    let Some(depth) = args.per_dir else {
+        return vec![TodoTarget {
+            todo_path: args.todo_path.clone(),
+            dir: None,
+            severity_dir: None,
+            todos,
+            scanned_files: files,
+        }];
+    };
+    let file_name = args
+        .todo_path
+        .file_name()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("TODO.md"));
+    let new_target = |dir: PathBuf| TodoTarget {
+        todo_path: dir.join(&file_name),
+        dir: Some(dir),
+        severity_dir: None,
+        todos: Vec::new(),
+        scanned_files: Vec::new(),
+    };
+
+    let mut partitions: BTreeMap<PathBuf, TodoTarget> = BTreeMap::new();
+    for file in &files {
+        if let Some(dir) = partition_dir(file, depth, workdir) {
+            partitions
+                .entry(dir.clone())
+                .or_insert_with(|| new_target(dir))
+                .scanned_files
+                .push(file.clone());
+        }
+    }
+    let mut root = TodoTarget {
+        todo_path: args.todo_path.clone(),
+        dir: None,
+        severity_dir: None,
+        todos: Vec::new(),
+        scanned_files: files,
+    };
+    for item in todos {
+        match partition_dir(&item.file_path, depth, workdir) {
+            Some(dir) => partitions
+                .entry(dir.clone())
+                .or_insert_with(|| new_target(dir))
+                .todos
+                .push(item),
+            None => root.todos.push(item),
+        }
+    }
+
+    std::iter::once(root)
+        .chain(partitions.into_values())
+        .collect()
+}
+
+/// Split each target further according to `--severity-dir`: items of a
+/// mapped marker move to `<DIR>/TODO.md` beside the target's TODO.md.
+///
+/// Every mapped directory gets a target, even without items, and each is
+/// handed all of its parent's scanned files, so fixed items are dropped
+/// from it.
+fn split_by_severity(args: &ParsedArgs, targets: Vec<TodoTarget>) -> Vec<TodoTarget> {
+    if args.severity_dirs.is_empty() {
+        return targets;
+    }
+    let mut split = Vec::new();
+    for mut target in targets {
+        let base = target
+            .todo_path
+            .parent()
+            .unwrap_or(Path::new(""))
+            .to_path_buf();
+        let file_name = target
+            .todo_path
+            .file_name()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("TODO.md"));
+        let mut by_dir: BTreeMap<&Path, TodoTarget> = BTreeMap::new();
+        for (_, dir) in &args.severity_dirs {
+            by_dir.entry(dir).or_insert_with(|| TodoTarget {
+                todo_path: base.join(dir).join(&file_name),
+                dir: target.dir.clone(),
+                severity_dir: Some(dir.clone()),
+                todos: Vec::new(),
+                scanned_files: target.scanned_files.clone(),
+            });
+        }
+        let (mapped, unmapped) = std::mem::take(&mut target.todos)
+            .into_iter()
+            .partition::<Vec<_>, _>(|item| severity_dir(args, &item.marker).is_some());
+        for item in mapped {
+            let dir = severity_dir(args, &item.marker).expect("partitioned as mapped");
+            by_dir
+                .get_mut(dir)
+                .expect("every mapped directory has a target")
+                .todos
+                .push(item);
+        }
+        target.todos = unmapped;
+        split.push(target);
+        split.extend(by_dir.into_values());
+    }
+    split
+}
+
+/// The `--severity-dir` directory `marker` is mapped to, if any.
+fn severity_dir<'a>(args: &'a ParsedArgs, marker: &str) -> Option<&'a Path> {
+    args.severity_dirs
+        .iter()
+        .find(|(mapped, _)| mapped == marker)
+        .map(|(_, dir)| dir.as_path())
+}
+
+/// Directory owning `file` under `--per-dir <depth>`: its ancestor `depth`
+/// levels below the repository root. `None` when the file is not nested
+/// that deep and therefore belongs to the root TODO.md. Absolute paths are
+/// measured from `workdir`; the returned directory keeps the same form
+/// (absolute or relative) as `file`.
+fn partition_dir(file: &Path, depth: usize, workdir: Option<&Path>) -> Option<PathBuf> {
+    let relative = workdir
+        .and_then(|w| file.strip_prefix(w).ok())
+        .unwrap_or(file);
+    let levels = relative
+        .components()
+        .filter(|c| matches!(c, Component::Normal(_)))
+        .count();
+    if levels <= depth {
+        return None;
+    }
+    file.ancestors().nth(levels - depth).map(Path::to_path_buf)
+}
+
+fn sync_target(
+    args: &ParsedArgs,
+    repo: &Repository,
+    git_ops: &dyn GitOpsTrait,
+    target: TodoTarget,
+    closed: &[(String, PathBuf, usize)],
+) -> Result<(), String> {
+    // Don't litter packages (or severity directories) without TODOs with
+    // empty files, nor the root TODO.md under `--no-create`.
+    if (!target.is_root() || args.no_create)
+        && target.todos.is_empty()
+        && !target.todo_path.exists()
+    {
+        return Ok(());
+    }
+    if target.severity_dir.is_some() {
+        if let Some(parent) = target.todo_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Error creating {}: {e}", parent.display()))?;
+        }
+    }
+    if !target.is_root() {
+        ensure_todo_path_exists(&target.todo_path)?;
+    }
+    let todo_content_before = std::fs::read_to_string(&target.todo_path).ok();
+    let entries_before = change_log_entries(args, &target.todo_path);
+    let options = WriteOptions {
+        closed: closed.to_vec(),
+        ..target.write_options(args)
+    };
+
+    if let Err(err) = todo_md::sync_todo_file_with_options(
+        &target.todo_path,
+        target.todos,
+        target.scanned_files,
+        &options,
+    ) {
+        info!("There was an error updating TODO.md: {err}");
+        if args.merge_into {
+            // A full rescan would overwrite the sections other runs
+            // contributed, which is exactly what --merge-into promises not
+            // to do.
+            return Err(format!(
+                "Error merging into {}: {err}",
+                target.todo_path.display()
+            ));
+        }
+        sync_fallback_full_rescan(
+            args,
+            repo,
+            git_ops,
+            &target.todo_path,
+            &target.dir,
+            &target.severity_dir,
+            &options,
+        );
+    }
+
+    append_change_log(args, &target.todo_path, entries_before)?;
     if args.auto_add {
-        maybe_stage_todo_file(&args.todo_path, &repo, git_ops, &todo_content_before)?;
+        maybe_stage_todo_file(&target.todo_path, repo, git_ops, &todo_content_before)?;
     }
     Ok(())
 }
 
+/// The entries of `todo_path` for `--change-log`, or nothing when no change
+/// log was asked for. A missing or unparsable file counts as empty.
+fn change_log_entries(args: &ParsedArgs, todo_path: &Path) -> Vec<MarkedItem> {
+    if args.change_log.is_none() {
+        return Vec::new();
+    }
+    todo_md::read_todo_file(todo_path).unwrap_or_default()
+}
+
+/// `--change-log`: append a timestamped record of the entries `todo_path`
+/// gained and lost since `before` was read. Runs that change nothing
+/// append nothing.
+fn append_change_log(
+    args: &ParsedArgs,
+    todo_path: &Path,
+    before: Vec<MarkedItem>,
+) -> Result<(), String> {
+    let Some(log_path) = &args.change_log else {
+        return Ok(());
+    };
+    let after = change_log_entries(args, todo_path);
+    let (added, removed) = todo_md::diff_entries(&before, &after);
+    if added.is_empty() && removed.is_empty() {
+        return Ok(());
+    }
+    let now = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let mut record = format!("{now} {}\n", todo_path.display());
+    for (sign, items) in [('+', &added), ('-', &removed)] {
+        for item in items {
+            record.push_str(&format!(
+                "{sign} {}:{}: [{}] {}\n",
+                item.file_path.display(),
+                item.line_number,
+                item.marker,
+                item.message
+            ));
+        }
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)
+        .and_then(|mut file| file.write_all(record.as_bytes()))
+        .map_err(|e| format!("failed to write {}: {e}", log_path.display()))
+}
+
 /// Last-resort recovery when `sync_todo_file` can't parse the existing
 /// TODO.md: rescan everything tracked and overwrite from scratch. Exit
 /// (rather than return Err) because at this point the TODO.md is already
 /// broken and propagating the error would leave the user with two failures
-/// to read.
-fn sync_fallback_full_rescan(args: &ParsedArgs, repo: &Repository, git_ops: &dyn GitOpsTrait) {
+/// to read. Under `--per-dir`, only the files owned by `dir` are kept;
+/// under `--severity-dir`, only the items of the target's markers.
+fn sync_fallback_full_rescan(
+    args: &ParsedArgs,
+    repo: &Repository,
+    git_ops: &dyn GitOpsTrait,
+    todo_path: &Path,
+    dir: &Option<PathBuf>,
+    severity: &Option<PathBuf>,
+    options: &WriteOptions,
+) {
     let all_files = match git_ops.get_tracked_files(repo) {
         Ok(files) => files,
         Err(e) => {
@@ -359,9 +1522,14 @@ fn sync_fallback_full_rescan(args: &ParsedArgs, repo: &Repository, git_ops: &dyn
             std::process::exit(1);
         }
     };
-    let filtered = filter_excluded_files(all_files, &args.exclusion_rules);
-    let todos = extract_todos_from_files(&filtered, &args.marker_config);
-    if let Err(err) = todo_md::write_todo_file(&args.todo_path, todos) {
+    let mut filtered = select_files(all_files, args);
+    if let Some(depth) = args.per_dir {
+        filtered.retain(|f| &partition_dir(f, depth, repo.workdir()) == dir);
+    }
+    let mut todos = extract_todos_from_files(&filtered, args);
+    retain_recent(&mut todos, args, repo, git_ops);
+    todos.retain(|item| severity_dir(args, &item.marker) == severity.as_deref());
+    if let Err(err) = todo_md::write_todo_file_with_options(todo_path, todos, options) {
         error!("Error updating TODO.md: {err}");
         std::process::exit(1);
     }
@@ -379,24 +1547,28 @@ fn maybe_stage_todo_file(
         return Ok(());
     }
     info!("TODO file was modified, staging it for commit");
+    stage_file(todo_path, repo, git_ops)
+}
 
+/// Add `path` (absolute, or relative to the work tree) to the index.
+fn stage_file(path: &Path, repo: &Repository, git_ops: &dyn GitOpsTrait) -> Result<(), String> {
     let repo_workdir = repo
         .workdir()
         .ok_or("Repository has no working directory")?;
-    let absolute = if todo_path.is_absolute() {
-        todo_path.to_path_buf()
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
     } else {
-        repo_workdir.join(todo_path)
+        repo_workdir.join(path)
     };
     let relative = absolute
         .strip_prefix(repo_workdir)
-        .map_err(|_| "TODO path is not within repository")?;
+        .map_err(|_| format!("{} is not within the repository", path.display()))?;
 
     if let Err(e) = git_ops.add_file_to_index(repo, relative) {
         // Warn but don't fail: staging failure shouldn't kill the commit.
-        error!("Warning: Failed to add TODO file to git index: {e}");
+        error!("Warning: Failed to add {relative:?} to git index: {e}");
     } else {
-        info!("Successfully staged TODO file: {relative:?}");
+        info!("Successfully staged {relative:?}");
     }
     Ok(())
 }
@@ -420,6 +1592,170 @@ fn build_cli() -> Command {
                 .global(true)
                 .default_value("TODO.md"),
         )
+        .arg(
+            Arg::new("repo_path")
+                .long("repo-path")
+                .value_name("DIR")
+                .help("Git repository to work in, for running outside its work tree. A relative --todo-path is still resolved against the current directory; give an absolute one together with --auto-add")
+                .default_value(".")
+                .global(true),
+        )
+        .arg(
+            Arg::new("relative_to_todo")
+                .long("relative-to-todo")
+                .help("Write link targets relative to the directory containing the TODO.md file instead of the repository root")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("project_roots")
+                .long("project-roots")
+                .value_name("DIR")
+                .help("Roots of independent projects in the repository: links to files under one of them are written relative to that root instead of the repository root. Give them in the same form as the scanned paths. Usage: --project-roots api web [-- files]")
+                .num_args(1..)
+                .action(ArgAction::Append)
+                .conflicts_with("no_links")
+                .global(true),
+        )
+        .arg(
+            Arg::new("scope")
+                .long("scope")
+                .help("Tag each entry with the nearest function/class definition above it, e.g. `* [src/lib.rs:12](src/lib.rs#L12) in `parse`: ...`. Built-in patterns cover Rust (fn, struct, enum, trait, impl) and Python (def, class)")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("scope_pattern")
+                .long("scope-pattern")
+                .value_name("REGEX")
+                .help("Definition pattern for --scope, used for every language instead of the built-in ones; its first capture group is the name")
+                .requires("scope")
+                .global(true),
+        )
+        .arg(
+            Arg::new("line_anchor")
+                .long("line-anchor")
+                .value_name("FMT")
+                .help("Line anchor appended to link targets, with {line} replaced by the line number: #L{line} (default; GitHub, GitLab) or e.g. #lines-{line} (Bitbucket)")
+                .global(true),
+        )
+        .arg(
+            Arg::new("no_links")
+                .long("no-links")
+                .help("Write entries as plain `* file:line: message`, without markdown link syntax")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["line_anchor", "relative_to_todo"])
+                .global(true),
+        )
+        .arg(
+            Arg::new("dedup_global")
+                .long("dedup-global")
+                .help("Collapse TODOs with the same marker and message in several files into one entry listing every location")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("group_by")
+                .long("group-by")
+                .value_name("KEY")
+                .help("Top-level grouping of TODO.md: marker (default), or assignee, taken from a leading @handle in the message (TODO: @alice ...); items without one go under (unassigned)")
+                .value_parser(["marker", "assignee"])
+                .default_value("marker")
+                .conflicts_with("dedup_global")
+                .global(true),
+        )
+        .arg(
+            Arg::new("sort_files")
+                .long("sort-files")
+                .value_name("KEY")
+                .help("Order of the file sections under each marker: name (default), or count, putting the files with the most items first (ties by name)")
+                .value_parser(["name", "count"])
+                .default_value("name")
+                .global(true),
+        )
+        .arg(
+            Arg::new("preamble")
+                .long("preamble")
+                .value_name("TEXT")
+                .help("Start TODO.md with TEXT (e.g. \"Auto-generated — do not edit manually\"), followed by a blank line. It is kept between <!-- preamble --> comment lines so TODO.md still parses.")
+                .global(true),
+        )
+        .arg(
+            Arg::new("preamble_file")
+                .long("preamble-file")
+                .value_name("PATH")
+                .help("Like --preamble, with the text read from PATH")
+                .conflicts_with("preamble")
+                .global(true),
+        )
+        .arg(
+            Arg::new("stable_ids")
+                .long("stable-ids")
+                .help("Start every entry's message with a short ID such as [T:a1b2c3], hashed from the marker and message, that stays the same when the TODO moves to another line")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("annotate")
+                .long("annotate")
+                .help("Rewrite each scanned TODO comment that has no ID yet to carry its --stable-ids ID after the marker (// TODO[T:a1b2c3]: ...), so the ID lives in the code too. Implies --stable-ids; with --auto-add the rewritten files are staged")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("uppercase_markers")
+                .long("uppercase-markers")
+                .help("Write every marker in uppercase, so todo, Todo and TODO items share one section")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("no_sort")
+                .long("no-sort")
+                .help("Keep discovery order instead of sorting: markers, files and entries appear in the order the files were passed and the lines were found. Existing entries keep their place. The output then depends on the argument order, so diffs are less stable.")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("keep_reviewed")
+                .long("keep-reviewed")
+                .help("When a scanned file's last TODO is removed, keep its section as a '## <file> — (none)' placeholder to show it was reviewed")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("timestamp")
+                .long("timestamp")
+                .help("Start TODO.md with a hidden <!-- generated: <RFC3339 time> --> line. It is only rewritten when the TODOs change.")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("flat")
+                .long("flat")
+                .help("Write TODO.md as a flat, header-less list of `path:line: [MARKER] message` lines, for piping into other tools")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all([
+                    "dedup_global",
+                    "summary_header",
+                    "timestamp",
+                    "keep_reviewed",
+                    "line_anchor",
+                    "relative_to_todo",
+                    "no_links",
+                    "scope",
+                    "preamble",
+                    "preamble_file",
+                ])
+                .global(true),
+        )
+        .arg(
+            Arg::new("summary_header")
+                .long("summary-header")
+                .help("Start TODO.md with a hidden HTML comment counting the items per marker, e.g. <!-- 15 TODO, 3 FIXME -->")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
         .arg(
             Arg::new("markers")
                 .short('m')
@@ -436,6 +1772,13 @@ fn build_cli() -> Command {
                 .num_args(0..)
                 .action(ArgAction::Append),
         )
+        .arg(
+            Arg::new("no_create")
+                .long("no-create")
+                .help("Don't create TODO.md if it doesn't exist and there are no TODOs to write into it")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
         .arg(
             Arg::new("auto_add")
                 .long("auto-add")
@@ -451,6 +1794,15 @@ fn build_cli() -> Command {
                 .action(ArgAction::Append)
                 .global(true),
         )
+        .arg(
+            Arg::new("include")
+                .long("include")
+                .value_name("EXT")
+                .help("Only scan files with these extensions (e.g. rs,py), after exclusions. Special file names count as their language, e.g. dockerfile. Can be specified multiple times.")
+                .value_delimiter(',')
+                .action(ArgAction::Append)
+                .global(true),
+        )
         .arg(
             Arg::new("exclude_dir")
                 .long("exclude-dir")
@@ -459,6 +1811,207 @@ fn build_cli() -> Command {
                 .action(ArgAction::Append)
                 .global(true),
         )
+        .arg(
+            Arg::new("explain_excludes")
+                .long("explain-excludes")
+                .help("Print every excluded file and the pattern that excluded it to stderr, to debug --exclude and --exclude-dir rules")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("parser_override")
+                .long("parser-override")
+                .value_name("EXT=LANG")
+                .help("Parse files with extension EXT using the LANG parser (e.g. --parser-override tpl=shell), taking precedence over the built-in mapping. Can be specified multiple times.")
+                .action(ArgAction::Append)
+                .global(true),
+        )
+        .arg(
+            Arg::new("exclude_from")
+                .long("exclude-from")
+                .value_name("FILE")
+                .help("Read --exclude patterns from FILE, one glob per line ('#' starts a comment line). Can be specified multiple times.")
+                .action(ArgAction::Append)
+                .global(true),
+        )
+        .arg(
+            Arg::new("known_issues")
+                .long("known-issues")
+                .value_name("FILE")
+                .help("Leave out the TODOs listed in FILE, one <file>:<line> reference per line (e.g. src/x.rs:10), because they already have an issue")
+                .global(true),
+        )
+        .arg(
+            Arg::new("dedupe_by")
+                .long("dedupe-by")
+                .value_name("KEY")
+                .help("Drop TODOs that repeat an earlier one: none (default) keeps all; message drops repeated messages; line drops repeats of the same file and line; full drops exact repeats (file, line, marker and message)")
+                .value_parser(["none", "message", "line", "full"])
+                .default_value("none")
+                .global(true),
+        )
+        .arg(
+            Arg::new("normalize_paths")
+                .long("normalize-paths")
+                .help("Drop '.' components and redundant separators from the given file paths (./src//main.rs becomes src/main.rs) before scanning, so TODO.md links stay clean. Symlinks are not resolved")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("skip_binary")
+                .long("skip-binary")
+                .help("Quietly skip files that look binary (a NUL byte near the start) instead of reporting them as unreadable")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("report_unsupported")
+                .long("report-unsupported")
+                .help("List the scanned files that were skipped because no parser handles their type, on stderr")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("ignore_tests")
+                .long("ignore-tests")
+                .help("Skip test code: adds **/tests/**, **/__tests__/**, *_test.*, test_*.* and *.test.* to the --exclude patterns")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("ignore_case_paths")
+                .long("ignore-case-paths")
+                .help("Match --exclude and --exclude-dir patterns case-insensitively (e.g. Build/ also excludes build/). Always on on Windows.")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("require_colon")
+                .long("require-colon")
+                .help("Only match markers followed directly by a colon (`TODO: ...`), ignoring a bare `TODO` in prose")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("multiline_join")
+                .long("multiline-join")
+                .value_name("SEP")
+                .help("Join the lines of a multi-line TODO with SEP instead of a space (e.g. --multiline-join \" / \")")
+                .allow_hyphen_values(true)
+                .global(true),
+        )
+        .arg(
+            Arg::new("marker_alias")
+                .long("marker-alias")
+                .value_name("ALIAS=MARKER")
+                .help("Also match ALIAS, but report it under MARKER (e.g. --marker-alias BUG=FIXME). Can be specified multiple times.")
+                .action(ArgAction::Append)
+                .global(true),
+        )
+        .arg(
+            Arg::new("continuation_indent")
+                .long("continuation-indent")
+                .value_name("N")
+                .help("Only merge a comment line into the preceding TODO if it is indented at least N spaces deeper than the marker line (default: any indentation)")
+                .value_parser(clap::value_parser!(usize))
+                .action(ArgAction::Set)
+                .global(true),
+        )
+        .arg(
+            Arg::new("since_days")
+                .long("since-days")
+                .value_name("N")
+                .help("Only report marked items whose line was last committed within the last N days (per git blame); uncommitted lines always count as recent")
+                .value_parser(clap::value_parser!(u32))
+                .global(true),
+        )
+        .arg(
+            Arg::new("close_from_commits")
+                .long("close-from-commits")
+                .value_name("N")
+                .help("Remove the TODO.md entries referenced as `Closes <MARKER> <file>:<line>` (e.g. Closes TODO src/x.rs:10) in the last N commit messages")
+                .value_parser(clap::value_parser!(usize))
+                .global(true),
+        )
+        .arg(
+            Arg::new("warn_threshold")
+                .long("warn-threshold")
+                .value_name("N")
+                .help("Print a warning for every scanned file containing more than N marked items")
+                .value_parser(clap::value_parser!(usize))
+                .action(ArgAction::Set)
+                .global(true),
+        )
+        .arg(
+            Arg::new("max_todos")
+                .long("max-todos")
+                .value_name("N")
+                .help("Exit non-zero when TODO.md ends up with more than N marked items. The file is still written.")
+                .value_parser(clap::value_parser!(usize))
+                .action(ArgAction::Set)
+                .global(true),
+        )
+        .arg(
+            Arg::new("change_log")
+                .long("change-log")
+                .value_name("PATH")
+                .help("Append a timestamped record of the entries each run added to (+) and removed from (-) TODO.md to PATH. Entries that only moved lines are not recorded. Applies to the default scan mode and --regenerate.")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("summary_json")
+                .long("summary-json")
+                .value_name("PATH")
+                .help("Also write a JSON summary of TODO.md (total, counts per marker and per file) to PATH. Applies to the default scan mode and --regenerate.")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .value_name("N")
+                .help("Number of threads used to extract TODOs (default: number of logical CPUs)")
+                .value_parser(clap::value_parser!(usize))
+                .action(ArgAction::Set)
+                .global(true),
+        )
+        .arg(
+            Arg::new("per_dir")
+                .long("per-dir")
+                .value_name("DEPTH")
+                .help("Monorepo mode: write a separate TODO.md into each directory DEPTH levels below the repository root, holding only the TODOs of files under it. Shallower files go to --todo-path. Applies to the default scan mode.")
+                .value_parser(clap::value_parser!(usize))
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("merge_strategy")
+                .long("merge-strategy")
+                .value_name("STRATEGY")
+                .help("How a scan updates the existing TODO.md: replace-scanned (default) replaces the entries of the scanned files only; refresh-all also re-extracts every other file TODO.md lists, so their line numbers stay current. Applies to the default scan mode.")
+                .value_parser(["replace-scanned", "refresh-all"])
+                .default_value("replace-scanned"),
+        )
+        .arg(
+            Arg::new("empty_todos")
+                .long("empty-todos")
+                .value_name("MODE")
+                .help("What to do with TODOs that have no message: error (default) fails before TODO.md is written; warn-fail leaves them out, writes TODO.md, then fails. Applies to the default scan mode.")
+                .value_parser(["error", "warn-fail"])
+                .default_value("error"),
+        )
+        .arg(
+            Arg::new("severity_dir")
+                .long("severity-dir")
+                .value_name("MARKER=DIR")
+                .help("Write the items of MARKER into DIR/TODO.md (next to --todo-path) instead of --todo-path, e.g. --severity-dir FIXME=critical. Repeatable; unmapped markers stay in --todo-path. Applies to the default scan mode.")
+                .action(ArgAction::Append),
+        )
+        .arg(
+            Arg::new("merge_into")
+                .long("merge-into")
+                .help("Merge only the scanned files' entries into the shared TODO.md, leaving other files' sections untouched. Safe to run concurrently (e.g. CI matrix jobs over disjoint file sets): the read-modify-write holds a file lock. Fails instead of rebuilding the file if it can't be parsed.")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["regenerate", "merge_driver"]),
+        )
         .arg(
             Arg::new("auto_install_merge_driver")
                 .long("auto-install-merge-driver")
@@ -470,14 +2023,21 @@ fn build_cli() -> Command {
                 .long("regenerate")
                 .help("Re-scan all tracked files and rewrite TODO.md from scratch. Wipes any existing content (including conflict markers).")
                 .action(ArgAction::SetTrue)
-                .conflicts_with_all(["install_merge_driver", "merge_driver"]),
+                .conflicts_with_all(["install_merge_driver", "merge_driver", "list"]),
+        )
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .help("CI gate: fail with a diff if TODO.md differs from what --regenerate would write. Never modifies TODO.md.")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["regenerate", "install_merge_driver", "merge_driver", "list", "merge_into", "per_dir", "severity_dir"]),
         )
         .arg(
             Arg::new("install_merge_driver")
                 .long("install-merge-driver")
                 .help("Register the TODO.md merge driver in .git/config and append a line to .gitattributes.")
                 .action(ArgAction::SetTrue)
-                .conflicts_with_all(["regenerate", "merge_driver"]),
+                .conflicts_with_all(["regenerate", "merge_driver", "list"]),
         )
         .arg(
             Arg::new("merge_driver")
@@ -485,6 +2045,47 @@ fn build_cli() -> Command {
                 .value_names(["BASE", "OURS", "THEIRS"])
                 .num_args(3)
                 .help("Git merge-driver entry point. Invoked by git as `--merge-driver %O %A %B`; regenerates TODO.md from working-tree source and writes it to OURS.")
-                .conflicts_with_all(["regenerate", "install_merge_driver"]),
+                .conflicts_with_all(["regenerate", "install_merge_driver", "list"]),
+        )
+        .arg(
+            Arg::new("list")
+                .long("list")
+                .help("Print the marked items grouped by marker to stdout instead of updating TODO.md. Scans the given files, or all tracked files when none are given.")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["regenerate", "install_merge_driver", "merge_driver"]),
+        )
+        .arg(
+            Arg::new("rewrite_links")
+                .long("rewrite-links")
+                .help("Fix stale line numbers in TODO.md: re-extract the files it references and update the entries whose comment moved, keeping everything else (including the order) as it is")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["regenerate", "check", "install_merge_driver", "merge_driver", "list"]),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help("Output format: markdown (default; update TODO.md) or gitlab (print a GitLab Code Quality JSON report to stdout instead). Scans the given files, or all tracked files when none are given.")
+                .value_parser(["markdown", "gitlab"])
+                .conflicts_with_all(["regenerate", "check", "install_merge_driver", "merge_driver", "list"]),
+        )
+        .arg(
+            Arg::new("list_languages")
+                .long("list-languages")
+                .value_name("FORMAT")
+                .help("Print every supported file extension and special file name (e.g. Dockerfile), one per line or as JSON, then exit")
+                .value_parser(["text", "json"])
+                .num_args(0..=1)
+                .default_missing_value("text")
+                .conflicts_with_all(["regenerate", "install_merge_driver", "merge_driver", "list", "check", "format"]),
+        )
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .value_name("WHEN")
+                .help("Colorize --list output: auto (default; off when NO_COLOR is set or stdout is not a terminal), always, or never")
+                .value_parser(["auto", "always", "never"])
+                .default_value("auto")
+                .global(true),
         )
 }
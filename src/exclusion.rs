@@ -7,6 +7,16 @@ use globset::Glob;
 use log::info;
 use std::path::{Path, PathBuf};
 
+/// Globs `--ignore-tests` adds to the `--exclude` patterns: test
+/// directories and the common test-file naming schemes.
+pub const TEST_PATH_PATTERNS: &[&str] = &[
+    "**/tests/**",
+    "**/__tests__/**",
+    "*_test.*",
+    "test_*.*",
+    "*.test.*",
+];
+
 /// Exclusion rule type
 #[derive(Debug, Clone)]
 enum ExclusionKind {
@@ -22,6 +32,25 @@ pub struct ExclusionRule {
     pattern: String,
     kind: ExclusionKind,
     glob: globset::GlobMatcher,
+    /// Compiled from the lowercased pattern; paths are lowercased too
+    /// before matching.
+    ignore_case: bool,
+}
+
+impl ExclusionRule {
+    /// The pattern as given on the command line (`--exclude-dir` patterns
+    /// with a trailing `/`).
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    fn is_match(&self, candidate: &str) -> bool {
+        if self.ignore_case {
+            self.glob.is_match(candidate.to_lowercase())
+        } else {
+            self.glob.is_match(candidate)
+        }
+    }
 }
 
 /// Build the exclusion matcher from CLI arguments
@@ -36,18 +65,37 @@ pub fn build_exclusion_matcher(
     exclude_patterns: Vec<String>,
     exclude_dir_patterns: Vec<String>,
 ) -> Result<Vec<ExclusionRule>, String> {
+    build_exclusion_matcher_with_case(exclude_patterns, exclude_dir_patterns, false)
+}
+
+/// Same as [`build_exclusion_matcher`], but with `ignore_case` set the
+/// rules match regardless of case (e.g. `Build/` excludes `build/`), as
+/// case-insensitive filesystems like Windows' expect.
+pub fn build_exclusion_matcher_with_case(
+    exclude_patterns: Vec<String>,
+    exclude_dir_patterns: Vec<String>,
+    ignore_case: bool,
+) -> Result<Vec<ExclusionRule>, String> {
+    let compile = |pattern: &str| {
+        let normalized = normalize_pattern(pattern);
+        if ignore_case {
+            Glob::new(&normalized.to_lowercase())
+        } else {
+            Glob::new(&normalized)
+        }
+    };
     let mut rules = Vec::new();
 
     // Add --exclude patterns
     for pattern in exclude_patterns {
-        let normalized = normalize_pattern(&pattern);
-        let glob = Glob::new(&normalized)
+        let glob = compile(&pattern)
             .map_err(|e| format!("Invalid exclude pattern '{}': {}", pattern, e))?
             .compile_matcher();
         rules.push(ExclusionRule {
             pattern: pattern.clone(),
             kind: ExclusionKind::Exclude,
             glob,
+            ignore_case,
         });
     }
 
@@ -58,14 +106,14 @@ pub fn build_exclusion_matcher(
         } else {
             format!("{}/", pattern)
         };
-        let normalized = normalize_pattern(&pattern_with_slash);
-        let glob = Glob::new(&normalized)
+        let glob = compile(&pattern_with_slash)
             .map_err(|e| format!("Invalid exclude-dir pattern '{}': {}", pattern, e))?
             .compile_matcher();
         rules.push(ExclusionRule {
             pattern: pattern_with_slash, // Store pattern with trailing slash
             kind: ExclusionKind::ExcludeDir,
             glob,
+            ignore_case,
         });
     }
 
@@ -87,6 +135,16 @@ fn normalize_pattern(pattern: &str) -> String {
 /// # Returns
 /// `true` if the path should be excluded (last match wins), `false` otherwise
 pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> bool {
+    matching_rule(path, is_dir, rules).is_some()
+}
+
+/// The rule that excludes `path`, if any: the last one matching it. See
+/// [`should_exclude`].
+pub fn matching_rule<'a>(
+    path: &Path,
+    is_dir: bool,
+    rules: &'a [ExclusionRule],
+) -> Option<&'a ExclusionRule> {
     // Try to match against both the full path and just the file/dir name components
     let path_str = path.to_str().unwrap_or("");
     let normalized_full_path = normalize_pattern(path_str);
@@ -100,7 +158,7 @@ pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> boo
         .filter_map(|c| c.as_os_str().to_str())
         .collect();
 
-    let mut excluded = false;
+    let mut excluded = None;
 
     for rule in rules {
         let mut matches = false;
@@ -113,13 +171,12 @@ pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> boo
             // This is a directory pattern - check if this is a dir OR if any parent is this dir
             if is_dir {
                 // Check if the directory itself matches
-                matches =
-                    rule.glob.is_match(&normalized_full_path) || rule.glob.is_match(file_name);
+                matches = rule.is_match(&normalized_full_path) || rule.is_match(file_name);
 
                 if !matches {
                     for i in 0..components.len() {
                         let partial_path = components[i..].join("/") + "/";
-                        if rule.glob.is_match(&partial_path) {
+                        if rule.is_match(&partial_path) {
                             matches = true;
                             break;
                         }
@@ -135,9 +192,7 @@ pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> boo
                         // Start from i+1 to get directory paths
                         let dir_path = components[i..j].join("/");
                         // Check if this directory path matches the pattern
-                        if rule.glob.is_match(&dir_path)
-                            || rule.glob.is_match(&(dir_path.clone() + "/"))
-                        {
+                        if rule.is_match(&dir_path) || rule.is_match(&(dir_path.clone() + "/")) {
                             matches = true;
                             break;
                         }
@@ -149,12 +204,12 @@ pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> boo
             }
         } else {
             // Regular file/dir pattern
-            matches = rule.glob.is_match(&normalized_full_path) || rule.glob.is_match(file_name);
+            matches = rule.is_match(&normalized_full_path) || rule.is_match(file_name);
 
             if !matches {
                 for i in 0..components.len() {
                     let partial_path = components[i..].join("/");
-                    if rule.glob.is_match(&partial_path) {
+                    if rule.is_match(&partial_path) {
                         matches = true;
                         break;
                     }
@@ -163,7 +218,7 @@ pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> boo
         }
 
         if matches {
-            excluded = true; // Last match wins
+            excluded = Some(rule); // Last match wins
         }
     }
 
@@ -181,13 +236,27 @@ pub fn should_exclude(path: &Path, is_dir: bool, rules: &[ExclusionRule]) -> boo
 pub fn filter_excluded_files(files: Vec<PathBuf>, rules: &[ExclusionRule]) -> Vec<PathBuf> {
     files
         .into_iter()
-        .filter(|file| {
-            let is_dir = file.is_dir();
-            let should_exclude_file = should_exclude(file, is_dir, rules);
-            if should_exclude_file {
-                info!("Excluding: {:?}", file);
+        .filter(|file| match matching_rule(file, file.is_dir(), rules) {
+            Some(rule) => {
+                info!("Excluding {:?} (matched {})", file, rule.pattern);
+                false
             }
-            !should_exclude_file
+            None => true,
+        })
+        .collect()
+}
+
+/// The files `rules` exclude, each with the pattern that excluded it, for
+/// `--explain-excludes`.
+pub fn explain_excluded_files(
+    files: &[PathBuf],
+    rules: &[ExclusionRule],
+) -> Vec<(PathBuf, String)> {
+    files
+        .iter()
+        .filter_map(|file| {
+            matching_rule(file, file.is_dir(), rules)
+                .map(|rule| (file.clone(), rule.pattern.clone()))
         })
         .collect()
 }
@@ -341,6 +410,57 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_matching_rule_reports_pattern() {
+        let rules =
+            build_exclusion_matcher(vec!["*.log".to_string()], vec!["build".to_string()]).unwrap();
+        let pattern =
+            |path: &str| matching_rule(Path::new(path), false, &rules).map(|r| r.pattern());
+        assert_eq!(pattern("logs/app.log"), Some("*.log"));
+        assert_eq!(pattern("build/out.rs"), Some("build/"));
+        // Last match wins.
+        assert_eq!(pattern("build/app.log"), Some("build/"));
+        assert_eq!(pattern("src/main.rs"), None);
+
+        let files = vec![PathBuf::from("src/main.rs"), PathBuf::from("build/out.rs")];
+        assert_eq!(
+            explain_excluded_files(&files, &rules),
+            vec![(PathBuf::from("build/out.rs"), "build/".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_should_exclude_ignore_case() {
+        let path = Path::new("build/output.rs");
+
+        let rules = build_exclusion_matcher(vec!["Build/".to_string()], vec![]).unwrap();
+        assert!(!should_exclude(path, false, &rules));
+
+        let rules =
+            build_exclusion_matcher_with_case(vec!["Build/".to_string()], vec![], true).unwrap();
+        assert!(should_exclude(path, false, &rules));
+        assert!(should_exclude(Path::new("BUILD/Output.rs"), false, &rules));
+        assert!(!should_exclude(Path::new("src/output.rs"), false, &rules));
+    }
+
+    #[test]
+    fn test_test_path_patterns() {
+        let patterns = TEST_PATH_PATTERNS.iter().map(|p| p.to_string()).collect();
+        let rules = build_exclusion_matcher(patterns, vec![]).unwrap();
+        for path in [
+            "tests/foo.rs",
+            "crates/core/tests/it.rs",
+            "web/__tests__/app.js",
+            "pkg/parser_test.go",
+            "test_parser.py",
+            "src/app.test.ts",
+        ] {
+            assert!(should_exclude(Path::new(path), false, &rules), "{path}");
+        }
+        assert!(!should_exclude(Path::new("src/foo.rs"), false, &rules));
+        assert!(!should_exclude(Path::new("src/latest.rs"), false, &rules));
+    }
+
     #[test]
     fn test_filter_excluded_files() {
         let rules = build_exclusion_matcher(vec!["*.log".to_string()], vec![]).unwrap();
@@ -1,4 +1,5 @@
-use git2::{DiffOptions, Error as GitError, Repository};
+use chrono::{DateTime, Utc};
+use git2::{DiffOptions, Error as GitError, ErrorCode, Repository};
 use log::{debug, info};
 use std::path::{Path, PathBuf};
 
@@ -8,6 +9,22 @@ pub trait GitOpsTrait {
     fn get_staged_files(&self, repo: &Repository) -> Result<Vec<PathBuf>, GitError>;
     fn get_tracked_files(&self, repo: &Repository) -> Result<Vec<PathBuf>, GitError>;
     fn add_file_to_index(&self, repo: &Repository, file_path: &Path) -> Result<(), GitError>;
+    /// Commit date of the commit that last touched line `line_number`
+    /// (1-based) of `file_path`, or `None` when the line isn't committed
+    /// yet.
+    fn blame_line(
+        &self,
+        repo: &Repository,
+        file_path: &Path,
+        line_number: usize,
+    ) -> Result<Option<DateTime<Utc>>, GitError>;
+    /// Messages of the last `count` commits reachable from HEAD, newest
+    /// first; empty before the first commit.
+    fn recent_commit_messages(
+        &self,
+        repo: &Repository,
+        count: usize,
+    ) -> Result<Vec<String>, GitError>;
 }
 
 /// Real implementation that uses git2 directly.
@@ -112,4 +129,61 @@ impl GitOpsTrait for GitOps {
         info!("Successfully added file to index: {file_path:?}");
         Ok(())
     }
+
+    /// Blames the working-tree contents of the file, so lines that aren't
+    /// committed yet (the usual case in a pre-commit run) come back as
+    /// `None` rather than being attributed to whatever HEAD had there.
+    /// Files unknown to HEAD are not committed at all, hence also `None`.
+    fn blame_line(
+        &self,
+        repo: &Repository,
+        file_path: &Path,
+        line_number: usize,
+    ) -> Result<Option<DateTime<Utc>>, GitError> {
+        debug!("Blaming {file_path:?}:{line_number}");
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| GitError::from_str("cannot blame in a bare repository"))?;
+        let relative = file_path.strip_prefix(workdir).unwrap_or(file_path);
+        let blame = match repo.blame_file(relative, None) {
+            Ok(blame) => blame,
+            Err(e) if e.code() == ErrorCode::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let contents = std::fs::read(workdir.join(relative))
+            .map_err(|e| GitError::from_str(&format!("failed to read {relative:?}: {e}")))?;
+        let blame = blame.blame_buffer(&contents)?;
+        let Some(hunk) = blame.get_line(line_number) else {
+            return Ok(None);
+        };
+        let commit_id = hunk.final_commit_id();
+        if commit_id.is_zero() {
+            return Ok(None);
+        }
+        let time = repo.find_commit(commit_id)?.time();
+        Ok(DateTime::from_timestamp(time.seconds(), 0))
+    }
+
+    fn recent_commit_messages(
+        &self,
+        repo: &Repository,
+        count: usize,
+    ) -> Result<Vec<String>, GitError> {
+        debug!("Reading the last {count} commit messages");
+        let mut revwalk = repo.revwalk()?;
+        match revwalk.push_head() {
+            Ok(()) => {}
+            Err(e) if matches!(e.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) => {
+                return Ok(Vec::new());
+            }
+            Err(e) => return Err(e),
+        }
+        revwalk
+            .take(count)
+            .map(|oid| {
+                let commit = repo.find_commit(oid?)?;
+                Ok(String::from_utf8_lossy(commit.message_bytes()).into_owned())
+            })
+            .collect()
+    }
 }
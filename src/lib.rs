@@ -1,10 +1,12 @@
 // Allow deprecated functions for backward compatibility in public API
 
+pub mod annotate;
 pub mod cli;
 pub mod exclusion;
 pub mod git_utils;
 pub mod logger;
 pub mod merge_driver;
+pub mod report;
 pub mod todo_md;
 pub mod todo_md_internal;
 
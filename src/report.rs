@@ -0,0 +1,191 @@
+//! Machine-readable reports of marked items for CI systems.
+
+use crate::MarkedItem;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// One entry of a GitLab Code Quality report. GitLab matches issues
+/// between pipelines by `fingerprint`, so it must not change while the
+/// comment stays put.
+#[derive(Serialize)]
+struct CodeQualityIssue<'a> {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: Location<'a>,
+}
+
+#[derive(Serialize)]
+struct Location<'a> {
+    path: std::borrow::Cow<'a, str>,
+    lines: Lines,
+}
+
+#[derive(Serialize)]
+struct Lines {
+    begin: usize,
+}
+
+/// GitLab severity for a marker: FIXME is `major`, TODO is `minor`, any
+/// other configured marker is `info`.
+fn severity(marker: &str) -> &'static str {
+    match marker {
+        "FIXME" => "major",
+        "TODO" => "minor",
+        _ => "info",
+    }
+}
+
+/// Stable fingerprint of an item: FNV-1a (64-bit) over its file path, line
+/// number and message, as 16 hex digits. Hand-rolled rather than using
+/// `std`'s `DefaultHasher`, whose output may change between Rust releases.
+pub fn fingerprint(item: &MarkedItem) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let path = item.file_path.to_string_lossy();
+    let line = item.line_number.to_string();
+    let mut hash = OFFSET_BASIS;
+    // NUL separators keep line 1 + "1b" apart from line 11 + "b".
+    for part in [path.as_bytes(), line.as_bytes(), item.message.as_bytes()] {
+        for &byte in part.iter().chain(std::iter::once(&0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+/// Render `todos` as a GitLab Code Quality report: a JSON array with one
+/// issue per item, sorted by file and line.
+pub fn gitlab_code_quality(todos: &[MarkedItem]) -> String {
+    let mut sorted: Vec<&MarkedItem> = todos.iter().collect();
+    sorted.sort_by(|a, b| (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number)));
+
+    let issues: Vec<CodeQualityIssue> = sorted
+        .into_iter()
+        .map(|item| CodeQualityIssue {
+            description: format!("{}: {}", item.marker, item.message),
+            check_name: format!("rusty-todo-md/{}", item.marker),
+            fingerprint: fingerprint(item),
+            severity: severity(&item.marker),
+            location: Location {
+                path: item.file_path.to_string_lossy(),
+                lines: Lines {
+                    begin: item.line_number,
+                },
+            },
+        })
+        .collect();
+    serde_json::to_string_pretty(&issues).expect("report serializes to JSON")
+}
+
+/// Counts behind `--summary-json`. Maps are ordered so the file is stable
+/// between runs.
+#[derive(Serialize)]
+struct Summary {
+    total: usize,
+    markers: BTreeMap<String, usize>,
+    files: BTreeMap<String, usize>,
+}
+
+/// Render a JSON summary of `todos`: the total, and the number of items per
+/// marker and per file.
+pub fn summary_json(todos: &[MarkedItem]) -> String {
+    let mut summary = Summary {
+        total: todos.len(),
+        markers: BTreeMap::new(),
+        files: BTreeMap::new(),
+    };
+    for item in todos {
+        *summary.markers.entry(item.marker.clone()).or_default() += 1;
+        *summary
+            .files
+            .entry(item.file_path.to_string_lossy().into_owned())
+            .or_default() += 1;
+    }
+    serde_json::to_string_pretty(&summary).expect("summary serializes to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn item(path: &str, line: usize, marker: &str, message: &str) -> MarkedItem {
+        MarkedItem {
+            file_path: PathBuf::from(path),
+            line_number: line,
+            message: message.to_string(),
+            marker: marker.to_string(),
+            scope: None,
+        }
+    }
+
+    #[test]
+    fn test_gitlab_code_quality_shape() {
+        let todos = vec![
+            item("src/b.rs", 3, "TODO", "split this module"),
+            item("src/a.rs", 7, "FIXME", "handle overflow"),
+            item("src/a.rs", 9, "HACK", "remove once upstream fixed"),
+        ];
+        let report: serde_json::Value = serde_json::from_str(&gitlab_code_quality(&todos)).unwrap();
+
+        let issues = report.as_array().expect("report is a JSON array");
+        assert_eq!(issues.len(), 3);
+
+        let first = &issues[0];
+        assert_eq!(first["description"], "FIXME: handle overflow");
+        assert_eq!(first["check_name"], "rusty-todo-md/FIXME");
+        assert_eq!(first["severity"], "major");
+        assert_eq!(first["location"]["path"], "src/a.rs");
+        assert_eq!(first["location"]["lines"]["begin"], 7);
+        assert_eq!(first["fingerprint"].as_str().unwrap().len(), 16);
+
+        assert_eq!(issues[1]["severity"], "info");
+        assert_eq!(issues[2]["severity"], "minor");
+        assert_eq!(issues[2]["location"]["path"], "src/b.rs");
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let a = item("src/a.rs", 7, "FIXME", "handle overflow");
+        let same = item("src/a.rs", 7, "FIXME", "handle overflow");
+        assert_eq!(fingerprint(&a), fingerprint(&same));
+        // Pinned so an accidental change to the hash is caught: GitLab would
+        // treat every existing issue as resolved and re-opened.
+        assert_eq!(fingerprint(&a), "219219febd57a1b1");
+
+        assert_ne!(
+            fingerprint(&a),
+            fingerprint(&item("src/a.rs", 8, "FIXME", "handle overflow"))
+        );
+        assert_ne!(
+            fingerprint(&a),
+            fingerprint(&item("src/a.rs", 7, "FIXME", "handle underflow"))
+        );
+        assert_ne!(
+            fingerprint(&item("a", 1, "TODO", "1b")),
+            fingerprint(&item("a", 11, "TODO", "b"))
+        );
+    }
+
+    #[test]
+    fn test_summary_json_counts() {
+        let todos = vec![
+            item("src/a.rs", 7, "FIXME", "handle overflow"),
+            item("src/a.rs", 9, "TODO", "split"),
+            item("src/b.rs", 3, "TODO", "rename"),
+        ];
+        let summary: serde_json::Value = serde_json::from_str(&summary_json(&todos)).unwrap();
+        assert_eq!(
+            summary,
+            serde_json::json!({
+                "total": 3,
+                "markers": {"FIXME": 1, "TODO": 2},
+                "files": {"src/a.rs": 2, "src/b.rs": 1},
+            })
+        );
+    }
+}
@@ -1,24 +1,125 @@
 use log::debug;
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::{marker::PhantomData, path::PathBuf};
 
+use crate::todo_extractor_internal::languages;
 use crate::todo_extractor_internal::languages::common::CommentParser;
 use crate::todo_extractor_internal::languages::common_syntax;
 use log::{error, info};
 use pest::Parser;
+use regex::Regex;
+use serde::Serialize;
 
 /// Represents a single found marked item.
-#[derive(Debug, PartialEq, Clone, Eq)]
+#[derive(Debug, PartialEq, Clone, Eq, Serialize)]
 pub struct MarkedItem {
     pub file_path: PathBuf,
     pub line_number: usize,
     pub message: String,
     pub marker: String,
+    /// Name of the nearest definition (function, class, ...) above the
+    /// item, when extracted with [`MarkerConfig::scope`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+impl MarkedItem {
+    /// The `@handle` the item is assigned to, taken from the start of its
+    /// message (`TODO: @alice fix this`, `TODO @alice: fix this`). Kept in
+    /// the message itself so TODO.md round-trips it unchanged.
+    pub fn assignee(&self) -> Option<&str> {
+        let handle = self
+            .message
+            .split_whitespace()
+            .next()?
+            .strip_prefix('@')?
+            .trim_end_matches([':', ',']);
+        (!handle.is_empty()).then_some(handle)
+    }
+
+    /// Whether the item sits at `line` of `file`, given the way people
+    /// write locations by hand: `file` may be a trailing part of the path
+    /// (`src/x.rs` matches `/repo/src/x.rs`).
+    pub fn is_at(&self, file: &Path, line: usize) -> bool {
+        self.line_number == line && self.file_path.ends_with(file)
+    }
+
+    /// `--stable-ids`: six hex digits of the 64-bit FNV-1a hash of the
+    /// marker and message, so the ID doesn't depend on the line or on the
+    /// Rust version.
+    pub fn stable_id(&self) -> String {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let text = format!("{}\0{}", self.marker, self.message);
+        for byte in text.bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        format!("{:06x}", hash >> 40)
+    }
+
+    /// Drop a leading `[T:<id>] ` from the message when it is the ID
+    /// [`MarkedItem::stable_id`] derives for the rest of the item anyway.
+    /// An ID that no longer matches, e.g. one `--annotate` wrote into a
+    /// comment before its message was edited, stays part of the message.
+    pub fn drop_derived_id(&mut self) {
+        let Some((id, rest)) = split_stable_id(&self.message) else {
+            return;
+        };
+        let Some(rest) = rest.strip_prefix(' ') else {
+            return;
+        };
+        let derived = MarkedItem {
+            message: rest.to_string(),
+            ..self.clone()
+        };
+        if derived.stable_id() == id {
+            *self = derived;
+        }
+    }
+}
+
+/// Split a leading `[T:<id>]` tag, as written by `--stable-ids` and
+/// `--annotate`, off `text`: the six hex digit ID and whatever follows the
+/// `]`. `None` when `text` doesn't start with a tag.
+pub(crate) fn split_stable_id(text: &str) -> Option<(&str, &str)> {
+    let rest = text.strip_prefix("[T:")?;
+    let (id, rest) = rest.split_at_checked(6)?;
+    if !id.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some((id, rest.strip_prefix(']')?))
 }
 
 /// Configuration for comment markers.
 pub struct MarkerConfig {
     pub markers: Vec<String>,
+    /// Minimum number of leading spaces, relative to the marker line, for a
+    /// comment line to continue the marker's block. `None` accepts any
+    /// indented line.
+    pub continuation_indent: Option<usize>,
+    /// Extra keywords that are matched like markers but reported under a
+    /// canonical marker, e.g. `BUG -> FIXME`.
+    pub aliases: BTreeMap<String, String>,
+    /// Extension -> parser mappings consulted before the built-in table,
+    /// e.g. `tpl -> shell` (`--parser-override`).
+    pub parser_overrides: BTreeMap<String, ParserFn>,
+    /// Tag every item with the name of the nearest definition at or above
+    /// its line (`--scope`). Definitions are found with `scope_pattern`, or
+    /// the language's default when there is one.
+    pub scope: bool,
+    /// Definition pattern whose first capture group is the name; overrides
+    /// the per-language defaults for every file.
+    pub scope_pattern: Option<Regex>,
+    /// Only match a marker immediately followed by `:` (`TODO: ...`), so a
+    /// bare `TODO` word in prose is ignored (`--require-colon`).
+    pub require_colon: bool,
+    /// Put between the lines of a multi-line item when they are merged into
+    /// one message (`--multiline-join`). Defaults to a single space.
+    pub multiline_join: String,
+    /// Skip files that look binary (a NUL byte near the start) instead of
+    /// reporting them unreadable (`--skip-binary`).
+    pub skip_binary: bool,
 }
 
 impl MarkerConfig {
@@ -28,7 +129,34 @@ impl MarkerConfig {
             .into_iter()
             .map(|m| m.trim().trim_end_matches(':').trim().to_string())
             .collect();
-        MarkerConfig { markers }
+        MarkerConfig {
+            markers,
+            continuation_indent: None,
+            aliases: BTreeMap::new(),
+            parser_overrides: BTreeMap::new(),
+            scope: false,
+            scope_pattern: None,
+            require_colon: false,
+            multiline_join: " ".to_string(),
+            skip_binary: false,
+        }
+    }
+
+    /// Every keyword that starts a marked item: the markers, then the aliases.
+    pub(crate) fn match_patterns(&self) -> Vec<String> {
+        self.markers
+            .iter()
+            .chain(self.aliases.keys())
+            .cloned()
+            .collect()
+    }
+
+    /// The marker an item is reported under when `matched` started it.
+    fn canonical_marker(&self, matched: String) -> String {
+        match self.aliases.get(&matched) {
+            Some(canonical) => canonical.clone(),
+            None => matched,
+        }
     }
 }
 
@@ -36,6 +164,14 @@ impl Default for MarkerConfig {
     fn default() -> Self {
         MarkerConfig {
             markers: vec!["TODO".to_string()],
+            continuation_indent: None,
+            aliases: BTreeMap::new(),
+            parser_overrides: BTreeMap::new(),
+            scope: false,
+            scope_pattern: None,
+            require_colon: false,
+            multiline_join: " ".to_string(),
+            skip_binary: false,
         }
     }
 }
@@ -149,8 +285,340 @@ fn flatten_comment_lines(lines: &[CommentLine]) -> Vec<CommentLine> {
     flattened
 }
 
+/// Signature shared by every language's `CommentParser::parse_comments`.
+pub type ParserFn = fn(&str) -> Vec<CommentLine>;
+
+/// Every supported extension and the parser that handles it. This is the
+/// single source of truth for both [`get_parser_for_extension`] and
+/// `--list-languages`, so the two can't drift.
+const LANGUAGES: &[(&[&str], ParserFn)] = &[
+    // Python-style comments (# only)
+    (&["py"], languages::python::PythonParser::parse_comments),
+    // Rust-style comments (// and /* */)
+    (&["rs"], languages::rust::RustParser::parse_comments),
+    // JavaScript and similar C-style comment languages (// and /* */)
+    (
+        &["js", "jsx", "mjs"],
+        languages::js::JsParser::parse_comments,
+    ),
+    // Other C-style comment languages (using JS parser for // and /* */ comments)
+    (
+        &[
+            "ts", "tsx", "java", "cpp", "hpp", "cc", "hh", "cs", "swift", "kt", "kts", "json",
+        ],
+        languages::js::JsParser::parse_comments,
+    ),
+    // Solidity: C-style comments plus NatSpec (/// and /** */, @notice/@dev tags dropped)
+    (
+        &["sol"],
+        languages::solidity::SolidityParser::parse_comments,
+    ),
+    // Haxe: C-style comments; '${x}' interpolation stays inside its string
+    (&["hx"], languages::js::JsParser::parse_comments),
+    // FlatBuffers schemas: C-style comments (// and /* */)
+    (&["fbs"], languages::js::JsParser::parse_comments),
+    // D: C-style comments plus nestable /+ +/ block comments
+    (&["d"], languages::d::DParser::parse_comments),
+    // Go-style comments (similar to C-style but with specific handling)
+    (&["go"], languages::go::GoParser::parse_comments),
+    // Hash-style comment languages (# only, using Python parser for line comments)
+    (&["sh"], languages::shell::ShellParser::parse_comments),
+    // Cython: Python comments; C-level declarations read as plain code
+    (
+        &["pyx", "pxd", "pxi"],
+        languages::python::PythonParser::parse_comments,
+    ),
+    // Starlark (Bazel): Python's # comments and strings (BUILD and
+    // WORKSPACE via EXACT_FILENAMES)
+    (&["bzl"], languages::python::PythonParser::parse_comments),
+    // Nushell and fish: # comments only where a word could start; strings
+    // may nest others inside (...) interpolation
+    (
+        &["nu", "fish"],
+        languages::nushell::NushellParser::parse_comments,
+    ),
+    (&["toml"], languages::toml::TomlParser::parse_comments),
+    (
+        &["dockerfile"],
+        languages::dockerfile::DockerfileParser::parse_comments,
+    ),
+    (&["tcl"], languages::tcl::TclParser::parse_comments),
+    (&["rego"], languages::rego::RegoParser::parse_comments),
+    // CMake: # line and #[[ ]] bracket comments (CMakeLists.txt via SPECIAL_FILENAMES)
+    (&["cmake"], languages::cmake::CmakeParser::parse_comments),
+    // Meson: # line comments, '...' and '''...''' strings (meson.build via SPECIAL_FILENAMES)
+    (&["meson"], languages::meson::MesonParser::parse_comments),
+    // Apache Thrift IDL: //, # and /* */ comments
+    (&["thrift"], languages::thrift::ThriftParser::parse_comments),
+    // AWK: # line comments, not inside strings or /regex/ literals
+    (&["awk"], languages::awk::AwkParser::parse_comments),
+    // systemd units and desktop entries: # and ; comments at the start of a line
+    (
+        &["service", "socket", "timer", "desktop"],
+        languages::systemd::SystemdParser::parse_comments,
+    ),
+    // Cap'n Proto schemas: # line comments, "..." strings
+    (&["capnp"], languages::capnp::CapnpParser::parse_comments),
+    // GDScript (Godot): # line comments, not inside strings or $NodePaths
+    (&["gd"], languages::gdscript::GdscriptParser::parse_comments),
+    // Gherkin (Cucumber): # comments at the start of a line, not in doc strings
+    (
+        &["feature"],
+        languages::gherkin::GherkinParser::parse_comments,
+    ),
+    // man pages (groff): .\" and \# comments, sections 1-9
+    (
+        &["1", "2", "3", "4", "5", "6", "7", "8", "9"],
+        languages::groff::GroffParser::parse_comments,
+    ),
+    // Elm and PureScript: -- line and nestable {- -} block comments
+    (&["elm", "purs"], languages::elm::ElmParser::parse_comments),
+    // Erlang: % line comments, not inside strings, quoted atoms or $char
+    // literals. Prolog's .pl clashes with Perl; map it with --parser-override.
+    (
+        &["erl", "hrl"],
+        languages::erlang::ErlangParser::parse_comments,
+    ),
+    // WebAssembly text: ;; line and nestable (; ;) block comments
+    (&["wat"], languages::wat::WatParser::parse_comments),
+    // Assembly: ; (NASM/MASM) and # (GNU as) line comments
+    (&["asm", "s"], languages::asm::AsmParser::parse_comments),
+    // CoffeeScript: # line comments and ### ### block comments
+    (&["coffee"], languages::coffee::CoffeeParser::parse_comments),
+    // Crystal: # line comments (not inside strings or #{} interpolation)
+    (&["cr"], languages::crystal::CrystalParser::parse_comments),
+    // Julia: # line comments and nestable #= =# block comments
+    (&["jl"], languages::julia::JuliaParser::parse_comments),
+    // Vim script: " comments at the start of a line
+    (&["vim"], languages::vim::VimParser::parse_comments),
+    // Nim: # line, ## doc and nestable #[ ]# block comments
+    (&["nim"], languages::nim::NimParser::parse_comments),
+    // YAML-style comments (# only)
+    (
+        &["yml", "yaml"],
+        languages::yaml::YamlParser::parse_comments,
+    ),
+    // Smalltalk: "..." comments, '...' strings
+    (
+        &["st"],
+        languages::smalltalk::SmalltalkParser::parse_comments,
+    ),
+    // Fortran-style comments (free-form ! only)
+    (
+        &["f90", "f95", "f03"],
+        languages::fortran::FortranParser::parse_comments,
+    ),
+    // SQL-style comments (-- for line comments)
+    (&["sql"], languages::sql::SqlParser::parse_comments),
+    // Markdown-style comments (HTML-style <!-- --> comments)
+    (&["md"], languages::markdown::MarkdownParser::parse_comments),
+    // reStructuredText: ".." comment blocks, not directives or targets
+    (&["rst"], languages::rst::RstParser::parse_comments),
+    // XML-style comments (<!-- --> only, CDATA sections are data)
+    (
+        &["xml", "xsd", "xsl"],
+        languages::xml::XmlParser::parse_comments,
+    ),
+    // Rails templates: ERB (<%# %> and <!-- -->), Haml and Slim (-# and / at line start)
+    (&["erb"], languages::erb::ErbParser::parse_comments),
+    (
+        &["haml", "slim"],
+        languages::haml::HamlParser::parse_comments,
+    ),
+    // Jinja2 and Twig templates ({# #} comments)
+    (
+        &["j2", "jinja", "jinja2", "twig"],
+        languages::jinja::JinjaParser::parse_comments,
+    ),
+    // Velocity templates (## line and #* *# block comments, not in #[[ ]]#)
+    (&["vm"], languages::velocity::VelocityParser::parse_comments),
+    // FreeMarker templates (<#-- --> and [#-- --] comments, not in noparse)
+    (
+        &["ftl"],
+        languages::freemarker::FreemarkerParser::parse_comments,
+    ),
+    // Liquid templates ({% comment %} blocks and inline {% # %} tags)
+    (&["liquid"], languages::liquid::LiquidParser::parse_comments),
+    // Handlebars and Mustache templates ({{! }} and {{!-- --}} comments)
+    (
+        &["hbs", "mustache"],
+        languages::handlebars::HandlebarsParser::parse_comments,
+    ),
+    // Pug templates (// and //- comments, continued by deeper indentation)
+    (&["pug", "jade"], languages::pug::PugParser::parse_comments),
+    // PlantUML (' line comments, /' '/ block comments)
+    (
+        &["puml", "plantuml"],
+        languages::plantuml::PlantUmlParser::parse_comments,
+    ),
+    // Mermaid (%% line comments)
+    (
+        &["mmd", "mermaid"],
+        languages::mermaid::MermaidParser::parse_comments,
+    ),
+    // Racket / Scheme (; line comments, nestable #| |# block comments)
+    (
+        &["rkt", "scm", "ss"],
+        languages::scheme::SchemeParser::parse_comments,
+    ),
+    // OCaml-style comments ((* *) only, nestable)
+    (
+        &["ml", "mli"],
+        languages::ocaml::OcamlParser::parse_comments,
+    ),
+    // Pascal/Delphi comments (//, (* *) and { }; {$...} directives are code)
+    (
+        &["pas", "dpr"],
+        languages::pascal::PascalParser::parse_comments,
+    ),
+    // ReasonML is OCaml with JS-like syntax, including // and /* */ comments
+    (&["re"], languages::js::JsParser::parse_comments),
+    // Objective-C / Objective-C++: C-style comments; @"..." literals are
+    // plain strings to the JS parser. `.m` is also MATLAB's extension; those
+    // files need `--parser-override m=matlab`.
+    (&["m", "mm"], languages::js::JsParser::parse_comments),
+    // Azure Bicep: C-style comments; '...' strings (with ${} interpolation)
+    // and ''' multi-line strings read as runs of plain JS strings.
+    (&["bicep"], languages::js::JsParser::parse_comments),
+];
+
+/// Files recognized by name rather than extension, mapped to the
+/// extension whose parser handles them.
+const SPECIAL_FILENAMES: &[(&str, &str)] = &[
+    ("Dockerfile", "dockerfile"),
+    ("CMakeLists.txt", "cmake"),
+    ("meson.build", "meson"),
+    ("meson_options.txt", "meson"),
+];
+
+/// Like [`SPECIAL_FILENAMES`], but matched exactly and case-sensitively:
+/// with variant suffixes, `BUILD` would also claim `build.rs` and
+/// `WORKSPACE` would claim `workspace.json`.
+const EXACT_FILENAMES: &[(&str, &str)] = &[
+    ("BUILD", "bzl"),
+    ("BUILD.bazel", "bzl"),
+    ("WORKSPACE", "bzl"),
+    ("WORKSPACE.bazel", "bzl"),
+];
+
+/// Every parser by name, for `--parser-override <ext>=<name>`. Names are
+/// the `languages` module names.
+const PARSERS: &[(&str, ParserFn)] = &[
+    ("asm", languages::asm::AsmParser::parse_comments),
+    ("awk", languages::awk::AwkParser::parse_comments),
+    ("capnp", languages::capnp::CapnpParser::parse_comments),
+    ("cmake", languages::cmake::CmakeParser::parse_comments),
+    ("coffee", languages::coffee::CoffeeParser::parse_comments),
+    ("crystal", languages::crystal::CrystalParser::parse_comments),
+    ("d", languages::d::DParser::parse_comments),
+    (
+        "dockerfile",
+        languages::dockerfile::DockerfileParser::parse_comments,
+    ),
+    ("elm", languages::elm::ElmParser::parse_comments),
+    ("erb", languages::erb::ErbParser::parse_comments),
+    ("erlang", languages::erlang::ErlangParser::parse_comments),
+    ("fortran", languages::fortran::FortranParser::parse_comments),
+    (
+        "gdscript",
+        languages::gdscript::GdscriptParser::parse_comments,
+    ),
+    ("gherkin", languages::gherkin::GherkinParser::parse_comments),
+    (
+        "freemarker",
+        languages::freemarker::FreemarkerParser::parse_comments,
+    ),
+    ("go", languages::go::GoParser::parse_comments),
+    ("groff", languages::groff::GroffParser::parse_comments),
+    ("haml", languages::haml::HamlParser::parse_comments),
+    (
+        "handlebars",
+        languages::handlebars::HandlebarsParser::parse_comments,
+    ),
+    ("jinja", languages::jinja::JinjaParser::parse_comments),
+    ("js", languages::js::JsParser::parse_comments),
+    ("julia", languages::julia::JuliaParser::parse_comments),
+    ("liquid", languages::liquid::LiquidParser::parse_comments),
+    (
+        "markdown",
+        languages::markdown::MarkdownParser::parse_comments,
+    ),
+    ("matlab", languages::matlab::MatlabParser::parse_comments),
+    ("mermaid", languages::mermaid::MermaidParser::parse_comments),
+    ("meson", languages::meson::MesonParser::parse_comments),
+    ("nim", languages::nim::NimParser::parse_comments),
+    ("nushell", languages::nushell::NushellParser::parse_comments),
+    ("ocaml", languages::ocaml::OcamlParser::parse_comments),
+    ("pascal", languages::pascal::PascalParser::parse_comments),
+    (
+        "plantuml",
+        languages::plantuml::PlantUmlParser::parse_comments,
+    ),
+    ("pug", languages::pug::PugParser::parse_comments),
+    ("python", languages::python::PythonParser::parse_comments),
+    ("rego", languages::rego::RegoParser::parse_comments),
+    ("rst", languages::rst::RstParser::parse_comments),
+    ("rust", languages::rust::RustParser::parse_comments),
+    ("scheme", languages::scheme::SchemeParser::parse_comments),
+    ("shell", languages::shell::ShellParser::parse_comments),
+    (
+        "smalltalk",
+        languages::smalltalk::SmalltalkParser::parse_comments,
+    ),
+    (
+        "solidity",
+        languages::solidity::SolidityParser::parse_comments,
+    ),
+    ("sql", languages::sql::SqlParser::parse_comments),
+    ("systemd", languages::systemd::SystemdParser::parse_comments),
+    ("tcl", languages::tcl::TclParser::parse_comments),
+    ("thrift", languages::thrift::ThriftParser::parse_comments),
+    ("toml", languages::toml::TomlParser::parse_comments),
+    (
+        "velocity",
+        languages::velocity::VelocityParser::parse_comments,
+    ),
+    ("vim", languages::vim::VimParser::parse_comments),
+    ("wat", languages::wat::WatParser::parse_comments),
+    ("xml", languages::xml::XmlParser::parse_comments),
+    ("yaml", languages::yaml::YamlParser::parse_comments),
+];
+
+/// The parser called `name` in [`PARSERS`].
+pub fn parser_by_name(name: &str) -> Option<ParserFn> {
+    PARSERS
+        .iter()
+        .find(|(parser_name, _)| *parser_name == name)
+        .map(|(_, parser)| *parser)
+}
+
+/// Every name [`parser_by_name`] accepts.
+pub fn parser_names() -> Vec<&'static str> {
+    PARSERS.iter().map(|(name, _)| *name).collect()
+}
+
+/// All extensions [`get_parser_for_extension`] accepts, in table order.
+pub fn supported_extensions() -> Vec<&'static str> {
+    LANGUAGES
+        .iter()
+        .flat_map(|(extensions, _)| extensions.iter().copied())
+        .collect()
+}
+
+/// Extension-less file names that are scanned anyway (e.g. `Dockerfile`).
+pub fn supported_filenames() -> Vec<&'static str> {
+    SPECIAL_FILENAMES
+        .iter()
+        .chain(EXACT_FILENAMES)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
 /// Determines the effective extension for a file, handling special cases like Dockerfile.
 ///
+/// Special file names also match with a variant suffix (`Dockerfile.dev`,
+/// `Dockerfile.prod`); `api.dockerfile` is covered by its extension.
+///
 /// - `path`: The file path to analyze.
 /// - Returns: The effective extension as a string.
 pub fn get_effective_extension(path: &Path) -> String {
@@ -160,6 +628,11 @@ pub fn get_effective_extension(path: &Path) -> String {
         .unwrap_or("")
         .to_lowercase();
 
+    let raw_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+    if let Some((_, ext)) = EXACT_FILENAMES.iter().find(|(name, _)| *name == raw_name) {
+        return ext.to_string();
+    }
+
     // Handle special filenames like Dockerfile which have no extension
     let file_name = path
         .file_name()
@@ -167,11 +640,16 @@ pub fn get_effective_extension(path: &Path) -> String {
         .unwrap_or("")
         .to_lowercase();
 
-    if extension.is_empty() && file_name == "dockerfile" {
-        "dockerfile".to_string()
-    } else {
-        extension
+    if let Some((_, ext)) = SPECIAL_FILENAMES.iter().find(|(name, _)| {
+        let name = name.to_lowercase();
+        file_name == name
+            || file_name
+                .strip_prefix(&name)
+                .is_some_and(|rest| rest.starts_with('.'))
+    }) {
+        return ext.to_string();
     }
+    extension
 }
 
 /// Returns the appropriate parser function for a given file extension.
@@ -182,49 +660,10 @@ pub fn get_parser_for_extension(
     extension: &str,
     file_path: &Path,
 ) -> Option<fn(&str) -> Vec<CommentLine>> {
-    let result: Option<fn(&str) -> Vec<CommentLine>> = match extension {
-        // Python-style comments (# only)
-        "py" => {
-            Some(crate::todo_extractor_internal::languages::python::PythonParser::parse_comments)
-        }
-
-        // Rust-style comments (// and /* */)
-        "rs" => Some(crate::todo_extractor_internal::languages::rust::RustParser::parse_comments),
-
-        // JavaScript and similar C-style comment languages (// and /* */)
-        "js" | "jsx" | "mjs" => {
-            Some(crate::todo_extractor_internal::languages::js::JsParser::parse_comments)
-        }
-
-        // Other C-style comment languages (using JS parser for // and /* */ comments)
-        "ts" | "tsx" | "java" | "cpp" | "hpp" | "cc" | "hh" | "cs" | "swift" | "kt" | "kts"
-        | "json" => Some(crate::todo_extractor_internal::languages::js::JsParser::parse_comments),
-
-        // Go-style comments (similar to C-style but with specific handling)
-        "go" => Some(crate::todo_extractor_internal::languages::go::GoParser::parse_comments),
-
-        // Hash-style comment languages (# only, using Python parser for line comments)
-        "sh" => Some(crate::todo_extractor_internal::languages::shell::ShellParser::parse_comments),
-        "toml" => Some(crate::todo_extractor_internal::languages::toml::TomlParser::parse_comments),
-        "dockerfile" => Some(
-            crate::todo_extractor_internal::languages::dockerfile::DockerfileParser::parse_comments,
-        ),
-
-        // YAML-style comments (# only)
-        "yml" | "yaml" => {
-            Some(crate::todo_extractor_internal::languages::yaml::YamlParser::parse_comments)
-        }
-
-        // SQL-style comments (-- for line comments)
-        "sql" => Some(crate::todo_extractor_internal::languages::sql::SqlParser::parse_comments),
-
-        // Markdown-style comments (HTML-style <!-- --> comments)
-        "md" => Some(
-            crate::todo_extractor_internal::languages::markdown::MarkdownParser::parse_comments,
-        ),
-
-        _ => None,
-    };
+    let result = LANGUAGES
+        .iter()
+        .find(|(extensions, _)| extensions.contains(&extension))
+        .map(|(_, parser)| *parser);
 
     // Log the result
     match &result {
@@ -260,7 +699,10 @@ pub fn extract_marked_items_with_parser(
     );
 
     // Continue with the existing logic to collect and merge marked items.
-    let marked_items = collect_marked_items_from_comment_lines(&comment_lines, config, path);
+    let mut marked_items = collect_marked_items_from_comment_lines(&comment_lines, config, path);
+    if config.scope {
+        assign_scopes(&mut marked_items, path, file_content, config);
+    }
     debug!(
         "extract_marked_items_with_parser: found {} marked items total",
         marked_items.len()
@@ -268,12 +710,79 @@ pub fn extract_marked_items_with_parser(
     marked_items
 }
 
+/// Default `--scope` definition patterns per extension; group 1 is the name.
+const SCOPE_PATTERNS: &[(&[&str], &str)] = &[
+    (
+        &["rs"],
+        r"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:(?:async|const|unsafe)\s+)*(?:fn|struct|enum|trait)\s+([A-Za-z_]\w*)",
+    ),
+    (
+        &["rs"],
+        r"^\s*(?:unsafe\s+)?impl(?:<[^>]*>)?\s+([A-Za-z_][\w:]*(?:<[^>]*>)?(?:\s+for\s+[A-Za-z_][\w:]*)?)",
+    ),
+    (&["py"], r"^\s*(?:async\s+)?(?:def|class)\s+([A-Za-z_]\w*)"),
+];
+
+/// Set each item's scope to the name captured from the last definition
+/// line at or above it. This is the nearest *preceding* definition, which
+/// for an item after the end of a nested function is not the enclosing one.
+fn assign_scopes(items: &mut [MarkedItem], path: &Path, content: &str, config: &MarkerConfig) {
+    let patterns: Vec<Regex> = match &config.scope_pattern {
+        Some(pattern) => vec![pattern.clone()],
+        None => {
+            let ext = get_effective_extension(path);
+            SCOPE_PATTERNS
+                .iter()
+                .filter(|(extensions, _)| extensions.contains(&ext.as_str()))
+                .map(|(_, pattern)| Regex::new(pattern).expect("valid scope pattern"))
+                .collect()
+        }
+    };
+    if patterns.is_empty() {
+        return;
+    }
+    // scopes[i] is the scope in effect on line i + 1.
+    let mut current = None;
+    let scopes: Vec<Option<String>> = content
+        .lines()
+        .map(|line| {
+            if let Some(name) = patterns
+                .iter()
+                .find_map(|re| re.captures(line))
+                .and_then(|caps| caps.get(1))
+            {
+                current = Some(name.as_str().trim().to_string());
+            }
+            current.clone()
+        })
+        .collect();
+    for item in items {
+        item.scope = item
+            .line_number
+            .checked_sub(1)
+            .and_then(|i| scopes.get(i))
+            .cloned()
+            .flatten();
+    }
+}
+
+/// The parser [`extract_marked_items_from_file`] uses for `file`: a
+/// `--parser-override` for its extension, else the built-in one. `None`
+/// means the file type is unsupported and the file is skipped.
+pub fn parser_for_file(file: &Path, marker_config: &MarkerConfig) -> Option<ParserFn> {
+    let effective_ext = get_effective_extension(file);
+    marker_config
+        .parser_overrides
+        .get(&effective_ext)
+        .copied()
+        .or_else(|| get_parser_for_extension(&effective_ext, file))
+}
+
 pub fn extract_marked_items_from_file(
     file: &Path,
     marker_config: &MarkerConfig,
 ) -> Result<Vec<MarkedItem>, String> {
-    let effective_ext = get_effective_extension(file);
-    let parser_fn = match get_parser_for_extension(&effective_ext, file) {
+    let parser_fn = match parser_for_file(file, marker_config) {
         Some(parser) => parser,
         None => {
             // Skip unsupported file types without reading content
@@ -282,8 +791,24 @@ pub fn extract_marked_items_from_file(
         }
     };
 
-    match std::fs::read_to_string(file) {
-        Ok(content) => {
+    let content = if marker_config.skip_binary {
+        std::fs::read(file).and_then(|bytes| {
+            if looks_binary(&bytes) {
+                return Ok(None);
+            }
+            String::from_utf8(bytes)
+                .map(Some)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        })
+    } else {
+        std::fs::read_to_string(file).map(Some)
+    };
+    match content {
+        Ok(None) => {
+            info!("Skipping binary file: {:?}", file);
+            Ok(Vec::new())
+        }
+        Ok(Some(content)) => {
             if content_has_conflict_markers(&content) {
                 // Use eprintln (not log::warn) so this surfaces without the
                 // user having to set RUST_LOG — these warnings are essential
@@ -294,7 +819,7 @@ pub fn extract_marked_items_from_file(
                 );
                 return Ok(Vec::new());
             }
-            if !content_may_contain_marker(&content, &marker_config.markers) {
+            if !content_may_contain_marker(&content, &marker_config.match_patterns()) {
                 info!(
                     "Skipping file with no marker substrings present: {:?}",
                     file
@@ -311,6 +836,15 @@ pub fn extract_marked_items_from_file(
     }
 }
 
+/// How much of a file [`looks_binary`] inspects, as git does.
+const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Whether `bytes` look like a binary file rather than text: a NUL byte in
+/// the first [`BINARY_SNIFF_LEN`] bytes.
+fn looks_binary(bytes: &[u8]) -> bool {
+    bytes.iter().take(BINARY_SNIFF_LEN).any(|&b| b == 0)
+}
+
 /// Cheap pre-parse check: return true iff at least one configured marker
 /// appears as a raw byte substring anywhere in `content`. Short-circuits the
 /// pest parse path for marker-free files (e.g. `package-lock.json`, long
@@ -360,16 +894,23 @@ pub fn collect_marked_items_from_comment_lines(
 ) -> Vec<MarkedItem> {
     // First, flatten multi-line comments and strip language-specific markers.
     let stripped_lines = strip_and_flatten(lines);
+    // Aliases start blocks just like markers do.
+    let patterns = config.match_patterns();
     // Group the lines into blocks based on marker lines and their indented continuations.
-    let blocks = group_lines_into_blocks_with_marker(stripped_lines, &config.markers);
-    // Convert each block into a MarkedItem.
+    let blocks = group_lines_into_blocks_with_marker(stripped_lines, &patterns, config);
+    // Convert each block into a MarkedItem, reporting aliases under their canonical marker.
     blocks
         .into_iter()
-        .map(|(line_number, marker, block)| MarkedItem {
-            file_path: path.to_path_buf(),
-            line_number,
-            message: process_block_lines(&block, &config.markers),
-            marker,
+        .map(|(line_number, marker, block)| {
+            let mut item = MarkedItem {
+                file_path: path.to_path_buf(),
+                line_number,
+                message: process_block_lines(&block, &marker, &config.multiline_join),
+                marker: config.canonical_marker(marker),
+                scope: None,
+            };
+            item.drop_derived_id();
+            item
         })
         .collect()
 }
@@ -389,21 +930,36 @@ fn strip_and_flatten(lines: &[CommentLine]) -> Vec<CommentLine> {
 /// - The line number where the block starts (i.e. the marker line)
 /// - The marker string that matched (always the base marker, no colon)
 /// - A vector of strings representing the block’s lines (with markers already stripped)
+///
+/// With `continuation_indent` set, a line only continues a block if it is indented at least
+/// that many columns deeper than the marker line. With `require_colon` set, a marker only
+/// starts a block when a colon follows it directly.
 fn group_lines_into_blocks_with_marker(
     lines: Vec<CommentLine>,
     markers: &[String],
+    config: &MarkerConfig,
 ) -> Vec<(usize, String, Vec<String>)> {
+    let continuation_indent = config.continuation_indent;
     let mut blocks = Vec::new();
     let mut current_block: Option<(usize, String, Vec<String>)> = None;
+    let mut marker_indent = 0;
 
     for cl in lines {
         let trimmed = cl.text.trim().to_string();
         // Try to match any marker at the start of the line.
-        // Accept if the marker is followed by nothing, a space, or a colon.
+        // Accept if the marker is followed by nothing, a space, or a colon
+        // (only a colon under `require_colon`), after an optional `[T:<id>]`
+        // tag written by `--annotate`.
         // Always store the base marker (no colon) in the result.
         let matched_marker = markers.iter().find_map(|base| {
             if let Some(rest) = trimmed.strip_prefix(base) {
-                if rest.is_empty() || rest.starts_with(' ') || rest.starts_with(':') {
+                let rest = split_stable_id(rest).map_or(rest, |(_, rest)| rest);
+                let accepted = if config.require_colon {
+                    rest.starts_with(':')
+                } else {
+                    rest.is_empty() || rest.starts_with(' ') || rest.starts_with(':')
+                };
+                if accepted {
                     return Some(base.clone());
                 }
             }
@@ -415,10 +971,15 @@ fn group_lines_into_blocks_with_marker(
                 blocks.push(block);
             }
             // Start a new block with the marker line.
+            marker_indent = leading_indent(&cl.text);
             current_block = Some((cl.line_number, marker, vec![trimmed]));
         } else if let Some((_, _, ref mut block_lines)) = current_block {
             // If the line is indented, treat it as a continuation of the current block.
-            if cl.text.starts_with(' ') || cl.text.starts_with('\t') {
+            let is_continuation = match continuation_indent {
+                Some(min) => leading_indent(&cl.text) >= marker_indent + min,
+                None => cl.text.starts_with(' ') || cl.text.starts_with('\t'),
+            };
+            if is_continuation {
                 block_lines.push(trimmed);
             } else {
                 // If not indented, close the current block.
@@ -435,6 +996,24 @@ fn group_lines_into_blocks_with_marker(
     blocks
 }
 
+/// Columns a tab advances to, for [`leading_indent`].
+const TAB_WIDTH: usize = 4;
+
+/// Width of the leading whitespace of `text` in columns. A tab moves to the
+/// next multiple of [`TAB_WIDTH`], so a tab and four spaces line up the same
+/// way however a file mixes them.
+fn leading_indent(text: &str) -> usize {
+    let mut column = 0;
+    for c in text.chars() {
+        match c {
+            ' ' => column += 1,
+            '\t' => column = (column / TAB_WIDTH + 1) * TAB_WIDTH,
+            _ => break,
+        }
+    }
+    column
+}
+
 /// Merges the given block lines into a single normalized message and removes the marker prefix.
 /// It also removes an optional colon (":") that immediately follows the marker.
 /// For example, if the block lines are:
@@ -443,21 +1022,58 @@ fn group_lines_into_blocks_with_marker(
 ///   ["TODO: Implement feature A", "more details"]
 /// the resulting message will be:
 ///   "Implement feature A more details"
-fn process_block_lines(lines: &[String], markers: &[String]) -> String {
-    let merged = lines.join(" ");
-    markers.iter().fold(merged, |acc, marker| {
-        if let Some(stripped) = acc.strip_prefix(marker) {
-            // If a colon immediately follows the marker, remove it.
-            let stripped = if let Some(rest) = stripped.strip_prefix(":") {
-                rest
-            } else {
-                stripped
+/// A bare marker (`TODO` or `TODO:` with nothing after it) gives an empty message.
+/// Lines are joined with `separator` (a space unless `--multiline-join` says otherwise);
+/// blank lines are dropped. A `[T:<id>]` tag right after the marker
+/// (`TODO[T:a1b2c3]: ...`) moves to the front of the message, where
+/// `--stable-ids` puts it.
+fn process_block_lines(lines: &[String], marker: &str, separator: &str) -> String {
+    let Some((first, rest)) = lines.split_first() else {
+        return String::new();
+    };
+    // Only the block's own marker is removed: a bare `TODO` always leaves
+    // an empty message, and other marker words after it stay in the text.
+    let first = first.trim();
+    let (id, first) = match first.strip_prefix(marker) {
+        Some(stripped) => {
+            let (id, stripped) = match split_stable_id(stripped) {
+                Some((id, stripped)) => (Some(id), stripped),
+                None => (None, stripped),
             };
-            stripped.trim().to_string()
-        } else {
-            acc
+            (id, stripped.strip_prefix(':').unwrap_or(stripped))
+        }
+        None => (None, first),
+    };
+    let parts: Vec<&str> = std::iter::once(first)
+        .chain(rest.iter().map(String::as_str))
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let message = collapse_whitespace(&parts.join(separator));
+    match id {
+        Some(id) if !message.is_empty() => format!("[T:{id}] {message}"),
+        _ => message,
+    }
+}
+
+/// Collapse runs of spaces and tabs to a single space, as left behind by
+/// trailing whitespace or indentation when lines are merged. `` `code` ``
+/// spans are kept as written.
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_code = false;
+    for c in text.chars() {
+        if c == '`' {
+            in_code = !in_code;
+        } else if !in_code && (c == ' ' || c == '\t') {
+            if !out.ends_with(' ') {
+                out.push(' ');
+            }
+            continue;
         }
-    })
+        out.push(c);
+    }
+    out
 }
 
 #[cfg(test)]
@@ -465,12 +1081,28 @@ mod aggregator_tests {
     use super::*;
     use crate::test_utils::{init_logger, test_extract_marked_items};
 
+    #[test]
+    fn test_marked_item_assignee() {
+        let item = |message: &str| MarkedItem {
+            file_path: PathBuf::from("a.rs"),
+            line_number: 1,
+            message: message.to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        };
+        assert_eq!(item("@alice fix this").assignee(), Some("alice"));
+        assert_eq!(item("@bob: fix this").assignee(), Some("bob"));
+        assert_eq!(item("ask @alice first").assignee(), None);
+        assert_eq!(item("@ nobody").assignee(), None);
+    }
+
     #[test]
     fn test_valid_rust_extension() {
         init_logger();
         let src = "// TODO: Implement feature X";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -483,6 +1115,7 @@ mod aggregator_tests {
         let src = "// TODO: Implement feature X";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.js"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -495,6 +1128,7 @@ mod aggregator_tests {
         let src = "// TODO: Add prop validation";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("component.jsx"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -507,6 +1141,7 @@ mod aggregator_tests {
         let src = "// TODO: Implement feature X";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("main.go"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -519,6 +1154,7 @@ mod aggregator_tests {
         let src = "// TODO: This should not be processed";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.unknown"), src, &config);
         assert_eq!(todos.len(), 0);
@@ -534,6 +1170,7 @@ mod aggregator_tests {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -553,12 +1190,229 @@ mod aggregator_tests {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(todos.len(), 1);
         assert_eq!(todos[0].message, "Improve API"); // Does not merge second line
     }
 
+    #[test]
+    fn test_continuation_indent_threshold() {
+        init_logger();
+        let src = r#"
+// TODO: Improve API
+//     keep the old names as aliases
+//  unrelated note about the module
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            continuation_indent: Some(2),
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
+        assert_eq!(todos.len(), 1);
+        // The weakly-indented note stays out of the block.
+        assert_eq!(
+            todos[0].message,
+            "Improve API keep the old names as aliases"
+        );
+    }
+
+    #[test]
+    fn test_continuation_indent_mixed_tabs_and_spaces() {
+        init_logger();
+        let src = "
+#\tTODO: rewrite the parser
+#         in safe Rust
+# FIXME: drop the cache
+#\tonce reads are fast
+";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            continuation_indent: Some(2),
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.py"), src, &config);
+        assert_eq!(todos.len(), 2);
+        // A tab counts up to the next multiple of four columns either way round.
+        assert_eq!(todos[0].message, "rewrite the parser in safe Rust");
+        assert_eq!(todos[1].message, "drop the cache once reads are fast");
+    }
+
+    #[test]
+    fn test_marker_alias_reported_under_canonical_marker() {
+        init_logger();
+        let src = r#"
+// BUG: off by one in the pager
+// FIXME: leaks a file handle
+"#;
+        let config = MarkerConfig {
+            markers: vec!["FIXME".to_string()],
+            aliases: BTreeMap::from([("BUG".to_string(), "FIXME".to_string())]),
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].marker, "FIXME");
+        assert_eq!(todos[0].message, "off by one in the pager");
+        assert_eq!(todos[1].marker, "FIXME");
+        assert_eq!(todos[1].message, "leaks a file handle");
+    }
+
+    #[test]
+    fn test_scope_rust() {
+        init_logger();
+        let src = r#"// TODO: crate-level note
+pub struct Config {
+    // TODO: validate paths
+    path: String,
+}
+
+impl Config {
+    pub fn load(path: &str) -> Self {
+        // TODO: read from disk
+        todo!()
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self { Self::load("") } // TODO: pick a real default
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            scope: true,
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("config.rs"), src, &config);
+        let scopes: Vec<Option<&str>> = todos.iter().map(|t| t.scope.as_deref()).collect();
+        assert_eq!(
+            scopes,
+            vec![None, Some("Config"), Some("load"), Some("default")]
+        );
+    }
+
+    #[test]
+    fn test_scope_python() {
+        init_logger();
+        let src = r#"class Loader:
+    async def fetch(self):
+        # TODO: retry on timeout
+        pass
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            scope: true,
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("loader.py"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].scope.as_deref(), Some("fetch"));
+
+        // Without --scope nothing is tagged.
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("loader.py"), src, &config);
+        assert_eq!(todos[0].scope, None);
+    }
+
+    #[test]
+    fn test_scope_custom_pattern() {
+        init_logger();
+        let src = "function render() {\n  // TODO: memoize\n}\n";
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            scope: true,
+            scope_pattern: Some(Regex::new(r"^\s*function\s+(\w+)").unwrap()),
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("view.js"), src, &config);
+        assert_eq!(todos[0].scope.as_deref(), Some("render"));
+    }
+
+    #[test]
+    fn test_multiline_message_whitespace_collapsed() {
+        init_logger();
+        let src = "// TODO: split\tthis   module  \n//      into two\t\n//   once `a  b` lands\n";
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(
+            todos[0].message,
+            "split this module into two once `a  b` lands"
+        );
+    }
+
+    #[test]
+    fn test_multiline_join_separator() {
+        init_logger();
+        let src = r#"
+// TODO: Fix bug
+//   Improve error handling
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            multiline_join: " / ".to_string(),
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Fix bug / Improve error handling");
+    }
+
+    #[test]
+    fn test_require_colon() {
+        init_logger();
+        let src = r#"
+// TODO do thing
+// TODO: do thing
+"#;
+        let mut config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            require_colon: true,
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "do thing");
+
+        config.require_colon = false;
+        let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
+        assert_eq!(todos.len(), 2);
+    }
+
+    #[test]
+    fn test_annotated_marker_id() {
+        init_logger();
+        let derived = MarkedItem {
+            file_path: PathBuf::from("file.rs"),
+            line_number: 2,
+            message: "Parse flags".to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        }
+        .stable_id();
+        let src = format!("\n// TODO[T:{derived}]: Parse flags\n// TODO[T:abc123]: Edited since\n");
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            require_colon: true,
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("file.rs"), &src, &config);
+        assert_eq!(todos.len(), 2);
+        // The ID is the one --stable-ids derives anyway, so it isn't kept.
+        assert_eq!(todos[0].message, "Parse flags");
+        // The message was edited after annotating: the old ID stays with it.
+        assert_eq!(todos[1].message, "[T:abc123] Edited since");
+    }
+
     #[test]
     fn test_todo_with_line_number() {
         init_logger();
@@ -568,6 +1422,7 @@ mod aggregator_tests {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -575,12 +1430,51 @@ mod aggregator_tests {
         assert_eq!(todos[0].message, "Implement caching");
     }
 
+    #[test]
+    fn test_bare_marker_has_empty_message() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string(), "FIXME".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("main.rs", "fn main() {}\n// TODO\n"),
+            ("main.rs", "fn main() {}\n/* TODO: */\n"),
+            ("main.py", "x = 1\n# TODO   \n"),
+            ("run.sh", "echo hi\n# TODO:\n"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}: {src:?}");
+            assert_eq!(todos[0].marker, "TODO", "{name}: {src:?}");
+            assert_eq!(todos[0].message, "", "{name}: {src:?}");
+            assert_eq!(todos[0].line_number, 2, "{name}: {src:?}");
+        }
+    }
+
+    #[test]
+    fn test_marker_words_after_the_marker_stay_in_message() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string(), "FIXME".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(
+            Path::new("main.rs"),
+            "// TODO: FIXME the parser first\n",
+            &config,
+        );
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO");
+        assert_eq!(todos[0].message, "FIXME the parser first");
+    }
+
     #[test]
     fn test_empty_input_no_todos() {
         init_logger();
         let src = "";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(todos.len(), 0);
@@ -592,6 +1486,7 @@ mod aggregator_tests {
         let src = "// TODO: Improve logging";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
 
@@ -605,6 +1500,7 @@ mod aggregator_tests {
         let src = "fn main() {}";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert!(todos.is_empty());
@@ -624,6 +1520,7 @@ let message = "TODO: This should not be detected";
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(todos.len(), 0);
@@ -638,6 +1535,7 @@ let message = "TODO: This should not be detected";
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
 
@@ -662,6 +1560,7 @@ let message = "TODO: This should not be detected";
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO".to_string(), "FIXME".to_string()],
+            ..Default::default()
         };
         let items = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(items.len(), 4);
@@ -683,6 +1582,7 @@ fn main() {}
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(
@@ -700,6 +1600,7 @@ fn main() {}
     "#;
         let config = MarkerConfig {
             markers: vec!["FIXME".to_string()],
+            ..Default::default()
         };
         let items = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(items.len(), 1);
@@ -714,6 +1615,7 @@ fn main() {}
     "#;
         let config = MarkerConfig {
             markers: vec!["FIXME".to_string()],
+            ..Default::default()
         };
         let items = test_extract_marked_items(Path::new("file.rs"), src, &config);
         assert_eq!(items.len(), 1);
@@ -733,6 +1635,7 @@ fn main() {}
     "#;
         let config = MarkerConfig {
             markers: vec!["TODO".to_string(), "FIXME".to_string()],
+            ..Default::default()
         };
         let items = test_extract_marked_items(Path::new("file.rs"), src, &config);
 
@@ -772,6 +1675,7 @@ fn some_function() {
 
         let config = MarkerConfig {
             markers: vec!["TODO".to_string(), "FIXME".to_string()],
+            ..Default::default()
         };
         let items = test_extract_marked_items(Path::new("file.rs"), src, &config);
 
@@ -807,6 +1711,7 @@ fn some_function() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.rs"), src, &config);
 
@@ -816,12 +1721,47 @@ fn some_function() {
         assert_eq!(todos[0].message, "add a new argument to specify what markers to look for like --markers \"TODO, FIXME, HACK\"");
     }
 
+    #[test]
+    fn test_valid_cython_extension() {
+        init_logger();
+        let src = r##"# cython: language_level=3
+cdef extern from "stdio.h":
+    int printf(const char *fmt, ...)
+
+# TODO: release the GIL here
+cdef int count(char *s, char c) nogil:
+    cdef int n = 0  # TODO: use size_t
+    cdef char *p = s
+    while p[0] != b'\0':
+        if p[0] == c'#':
+            n += 1
+        p += 1
+    return n
+
+cpdef greet(name):
+    printf(b"# TODO: not a comment %s\n", <char *>name)
+"##;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["fast.pyx", "fast.pxd", "fast.pxi"] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 2, "{name}");
+            assert_eq!(todos[0].line_number, 5);
+            assert_eq!(todos[0].message, "release the GIL here");
+            assert_eq!(todos[1].line_number, 7);
+            assert_eq!(todos[1].message, "use size_t");
+        }
+    }
+
     #[test]
     fn test_valid_sh_extension() {
         init_logger();
         let src = "# TODO: setup\nexit";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("script.sh"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -834,6 +1774,7 @@ fn some_function() {
         let src = "# TODO: conf\nkey: val";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("config.yaml"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -846,6 +1787,7 @@ fn some_function() {
         let src = "# TODO: fix\nkey=1";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("config.toml"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -858,6 +1800,7 @@ fn some_function() {
         let src = "-- TODO: q\nSELECT 1;";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("query.sql"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -870,6 +1813,7 @@ fn some_function() {
         let src = "<!-- TODO: doc -->";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("README.md"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -877,22 +1821,748 @@ fn some_function() {
     }
 
     #[test]
-    fn test_dockerfile_no_extension() {
+    fn test_supported_extensions_all_have_parsers() {
+        let extensions = supported_extensions();
+        for ext in ["rs", "py", "dockerfile"] {
+            assert!(extensions.contains(&ext), "missing {ext}");
+        }
+        for ext in extensions {
+            assert!(get_parser_for_extension(ext, Path::new("x")).is_some());
+        }
+        for name in supported_filenames() {
+            let ext = get_effective_extension(Path::new(name));
+            assert!(get_parser_for_extension(&ext, Path::new(name)).is_some());
+        }
+    }
+
+    #[test]
+    fn test_valid_tcl_extension() {
         init_logger();
-        let src = "# TODO: step\nFROM alpine";
+        let src = "# TODO: Implement feature X";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
-        let todos = test_extract_marked_items(Path::new("Dockerfile"), src, &config);
+        let todos = test_extract_marked_items(Path::new("script.tcl"), src, &config);
         assert_eq!(todos.len(), 1);
         assert_eq!(todos[0].marker, "TODO:");
     }
 
     #[test]
-    fn test_extract_marked_items_from_file_unsupported_extension() {
+    fn test_valid_fortran_extension() {
         init_logger();
+        let src = "! TODO: Implement feature X";
         let config = MarkerConfig {
-            markers: vec!["TODO".to_string()],
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("model.f03"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_xml_extension() {
+        init_logger();
+        let src = "<!-- TODO: Implement feature X -->";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("schema.xsd"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_erb_extension() {
+        init_logger();
+        let src = "<%# TODO: Implement feature X %>";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("index.html.erb"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_haml_extension() {
+        init_logger();
+        let src = "-# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("index.html.haml"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_pascal_extension() {
+        init_logger();
+        let src = "{ TODO: Implement feature X }";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("unit1.pas"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_rego_extension() {
+        init_logger();
+        let src = "# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("policy.rego"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_solidity_extension() {
+        init_logger();
+        let src = "// TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("Token.sol"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_julia_extension() {
+        init_logger();
+        let src = "#= TODO: Implement feature X =#";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("script.jl"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_coffee_extension() {
+        init_logger();
+        let src = "### TODO: Implement feature X ###";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("script.coffee"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_vim_extension() {
+        init_logger();
+        let src = "\" TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("script.vim"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_d_extension() {
+        init_logger();
+        let src = "/+ TODO: Implement feature X +/";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("script.d"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_smalltalk_extension() {
+        init_logger();
+        let src = "\"TODO: Implement feature X\"";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("script.st"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_nim_extension() {
+        init_logger();
+        let src = "#[ TODO: Implement feature X ]#";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("script.nim"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_crystal_extension() {
+        init_logger();
+        let src = "# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("app.cr"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_valid_cmake_extension() {
+        init_logger();
+        let src = "# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["CMakeLists.txt", "toolchain.cmake"] {
+            assert_eq!(get_effective_extension(Path::new(name)), "cmake");
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+        // Other .txt files stay unsupported.
+        assert_eq!(get_effective_extension(Path::new("notes.txt")), "txt");
+    }
+
+    #[test]
+    fn test_valid_jinja_extension() {
+        init_logger();
+        let src = "{# TODO: Implement feature X #}";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["index.html.j2", "base.twig"] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_liquid_extension() {
+        init_logger();
+        let src = "{% comment %} TODO: Implement feature X {% endcomment %}";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("theme.liquid"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_handlebars_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("view.hbs", "{{!-- TODO: Implement feature X --}}"),
+            ("view.mustache", "{{! TODO: Implement feature X }}"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_objc_extension() {
+        init_logger();
+        let src = r#"#import "AppDelegate.h"
+
+// TODO: move to a config file
+static NSString *const kURL = @"https://example.com/* TODO: not a comment */";
+
+@implementation AppDelegate
+/* TODO: handle
+   background launch */
+- (void)start {
+    [self performSelector:@selector(run:) withObject:@"// TODO: nor this"];
+}
+@end
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["AppDelegate.m", "Bridge.mm"] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 2, "{name}");
+            assert_eq!(todos[0].line_number, 3);
+            assert_eq!(todos[0].message, "move to a config file");
+            assert_eq!(todos[1].line_number, 7);
+            assert_eq!(todos[1].message, "handle background launch");
+        }
+    }
+
+    #[test]
+    fn test_valid_bicep_extension() {
+        init_logger();
+        let src = r#"// TODO: move to a module
+param location string = resourceGroup().location
+var url = 'https://${host}/api // TODO: not a comment'
+var script = '''
+  echo "/* TODO: nor this */"
+'''
+/* TODO: pin the
+   API version */
+resource st 'Microsoft.Storage/storageAccounts@2023-01-01' = {
+  name: '${prefix}st' // TODO: shorten the name
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("main.bicep"), src, &config);
+        assert_eq!(todos.len(), 3);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "move to a module");
+        assert_eq!(todos[1].line_number, 7);
+        assert_eq!(todos[1].message, "pin the API version");
+        assert_eq!(todos[2].line_number, 10);
+        assert_eq!(todos[2].message, "shorten the name");
+    }
+
+    #[test]
+    fn test_valid_pug_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["view.pug", "view.jade"] {
+            let todos = test_extract_marked_items(
+                Path::new(name),
+                "//- TODO: Implement feature X",
+                &config,
+            );
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_scheme_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("main.rkt", "; TODO: Implement feature X"),
+            ("main.scm", "#| TODO: Implement feature X |#"),
+            ("main.ss", ";;; TODO: Implement feature X"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_diagram_extensions() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("seq.puml", "' TODO: Implement feature X"),
+            ("seq.plantuml", "/' TODO: Implement feature X '/"),
+            ("flow.mmd", "%% TODO: Implement feature X"),
+            ("flow.mermaid", "%% TODO: Implement feature X"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_nushell_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["config.nu", "config.fish"] {
+            let todos =
+                test_extract_marked_items(Path::new(name), "# TODO: Implement feature X", &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_meson_extension() {
+        init_logger();
+        let src = "# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in [
+            "meson.build",
+            "subprojects/zlib/meson.build",
+            "meson_options.txt",
+        ] {
+            assert_eq!(get_effective_extension(Path::new(name)), "meson");
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_bzl_extension() {
+        init_logger();
+        let src = r#"load("//tools:defs.bzl", "cc_binary")
+
+# TODO: split into smaller targets
+cc_binary(
+    name = "app#main",
+    srcs = ["main.cc"],  # FIXME: glob the sources
+)
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        for name in ["BUILD", "pkg/BUILD.bazel", "WORKSPACE", "rules.bzl"] {
+            assert_eq!(get_effective_extension(Path::new(name)), "bzl");
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 2, "{name}");
+            assert_eq!(todos[0].line_number, 3);
+            assert_eq!(todos[0].message, "split into smaller targets");
+            assert_eq!(todos[1].line_number, 6);
+            assert_eq!(todos[1].message, "glob the sources");
+        }
+        // Only the exact names count: build.rs is still Rust.
+        assert_eq!(get_effective_extension(Path::new("build.rs")), "rs");
+        assert_eq!(get_effective_extension(Path::new("workspace.json")), "json");
+    }
+
+    #[test]
+    fn test_valid_asm_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("boot.asm", "; TODO: Implement feature X"),
+            ("boot.s", "# TODO: Implement feature X"),
+            ("boot.S", "# TODO: Implement feature X"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].marker, "TODO:");
+        }
+    }
+
+    #[test]
+    fn test_valid_schema_extensions() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("person.capnp", "# TODO: Implement feature X"),
+            ("monster.fbs", "// TODO: Implement feature X"),
+            ("monster.fbs", "/* TODO: Implement feature X */"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}: {src}");
+            assert_eq!(todos[0].message, "Implement feature X");
+        }
+    }
+
+    #[test]
+    fn test_valid_wat_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for src in [
+            ";; TODO: Implement feature X",
+            "(; TODO: Implement feature X ;)",
+        ] {
+            let todos = test_extract_marked_items(Path::new("module.wat"), src, &config);
+            assert_eq!(todos.len(), 1, "{src}");
+            assert_eq!(todos[0].message, "Implement feature X");
+        }
+    }
+
+    #[test]
+    fn test_valid_gdscript_extension() {
+        init_logger();
+        let src = "# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("player.gd"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_feature_extension() {
+        init_logger();
+        let src = "Feature: Login\n  # TODO: add scenario\n";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("login.feature"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "add scenario");
+    }
+
+    #[test]
+    fn test_valid_erlang_extension() {
+        init_logger();
+        let src = "% TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["server.erl", "records.hrl"] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].message, "Implement feature X");
+        }
+    }
+
+    #[test]
+    fn test_valid_rst_extension() {
+        init_logger();
+        let src = ".. TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("index.rst"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_velocity_extension() {
+        init_logger();
+        let src = "## TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("page.vm"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_freemarker_extension() {
+        init_logger();
+        let src = "<#-- TODO: Implement feature X -->";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("page.ftl"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_systemd_extension() {
+        init_logger();
+        let src = "[Service]\n# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("app.service"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_awk_extension() {
+        init_logger();
+        let src = "# TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("report.awk"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+    }
+
+    #[test]
+    fn test_valid_groff_extension() {
+        init_logger();
+        let src = ".\\\" TODO: Implement feature X";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["tool.1", "libfoo.3", "tool.conf.5", "admin.8"] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+            assert_eq!(todos[0].message, "Implement feature X");
+        }
+    }
+
+    #[test]
+    fn test_valid_elm_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for (name, src) in [
+            ("Main.elm", "-- TODO: Implement feature X"),
+            ("Main.elm", "{- TODO: Implement feature X -}"),
+            ("Main.purs", "-- TODO: Implement feature X"),
+            ("Main.purs", "{- TODO: Implement feature X -}"),
+        ] {
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}: {src}");
+            assert_eq!(todos[0].message, "Implement feature X");
+        }
+    }
+
+    #[test]
+    fn test_valid_thrift_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for src in [
+            "// TODO: Implement feature X",
+            "# TODO: Implement feature X",
+            "/* TODO: Implement feature X */",
+        ] {
+            let todos = test_extract_marked_items(Path::new("service.thrift"), src, &config);
+            assert_eq!(todos.len(), 1, "{src}");
+            assert_eq!(todos[0].message, "Implement feature X");
+        }
+    }
+
+    #[test]
+    fn test_valid_hx_extension() {
+        init_logger();
+        let src = r#"class Main {
+    // TODO: read the name from argv
+    static function main() {
+        var name = "world";
+        trace('Hello ${name + "// TODO: not a comment"}');
+        /* FIXME: trace is stripped
+           in release builds */
+    }
+    /* TODO: document the entry point */
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("Main.hx"), src, &config);
+        assert_eq!(todos.len(), 3);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "read the name from argv");
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "trace is stripped in release builds");
+        assert_eq!(todos[2].line_number, 9);
+        assert_eq!(todos[2].message, "document the entry point");
+    }
+
+    #[test]
+    fn test_valid_ocaml_extension() {
+        init_logger();
+        let src = "(* TODO: Implement feature X *)";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("main.ml"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "Implement feature X");
+
+        let src = "// TODO: Implement feature X";
+        let todos = test_extract_marked_items(Path::new("main.re"), src, &config);
+        assert_eq!(todos.len(), 1);
+    }
+
+    #[test]
+    fn test_dockerfile_no_extension() {
+        init_logger();
+        let src = "# TODO: step\nFROM alpine";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("Dockerfile"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].marker, "TODO:");
+    }
+
+    #[test]
+    fn test_dockerfile_variants() {
+        init_logger();
+        let src = "# TODO: step\nFROM alpine";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        for name in ["Dockerfile.dev", "api.dockerfile"] {
+            assert_eq!(get_effective_extension(Path::new(name)), "dockerfile");
+            let todos = test_extract_marked_items(Path::new(name), src, &config);
+            assert_eq!(todos.len(), 1, "{name}");
+        }
+        // Only a `.` suffix makes a variant.
+        assert_eq!(get_effective_extension(Path::new("Dockerfiles.md")), "md");
+    }
+
+    #[test]
+    fn test_extract_marked_items_from_file_unsupported_extension() {
+        init_logger();
+        let config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            ..Default::default()
         };
 
         // Test with an unsupported file extension
@@ -903,11 +2573,61 @@ fn some_function() {
         assert_eq!(result.unwrap().len(), 0);
     }
 
+    #[test]
+    fn test_extract_marked_items_from_file_parser_override() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("deploy.tpl");
+        std::fs::write(&file, "#!/bin/sh\n# TODO: quote the paths\ncp $SRC $DST\n").unwrap();
+
+        let mut config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            ..Default::default()
+        };
+        // Unknown extension: skipped.
+        assert!(extract_marked_items_from_file(&file, &config)
+            .unwrap()
+            .is_empty());
+
+        config
+            .parser_overrides
+            .insert("tpl".to_string(), parser_by_name("shell").unwrap());
+        let todos = extract_marked_items_from_file(&file, &config).unwrap();
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "quote the paths");
+    }
+
+    #[test]
+    fn test_parser_override_m_to_matlab() {
+        init_logger();
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file = temp_dir.path().join("solve.m");
+        std::fs::write(&file, "x = A \\ b; % TODO: use a sparse solver\n").unwrap();
+
+        let mut config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            ..Default::default()
+        };
+        // `.m` defaults to Objective-C, which doesn't know `%` comments.
+        assert!(extract_marked_items_from_file(&file, &config)
+            .unwrap()
+            .is_empty());
+
+        config
+            .parser_overrides
+            .insert("m".to_string(), parser_by_name("matlab").unwrap());
+        let todos = extract_marked_items_from_file(&file, &config).unwrap();
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "use a sparse solver");
+    }
+
     #[test]
     fn test_extract_marked_items_from_file_nonexistent_file() {
         init_logger();
         let config = MarkerConfig {
             markers: vec!["TODO".to_string()],
+            ..Default::default()
         };
 
         // Test with a file that doesn't exist (supported extension but unreadable)
@@ -920,11 +2640,35 @@ fn some_function() {
         assert!(error_msg.contains("nonexistent_file.rs"));
     }
 
+    #[test]
+    fn test_skip_binary_file() {
+        init_logger();
+        let temp_file = tempfile::Builder::new()
+            .suffix(".rs")
+            .tempfile()
+            .expect("Failed to create temp file");
+        std::fs::write(temp_file.path(), b"// TODO: not text\0\x01\xff\xfe")
+            .expect("Failed to write test content");
+        let mut config = MarkerConfig {
+            markers: vec!["TODO".to_string()],
+            ..Default::default()
+        };
+
+        // Without the flag the file is reported as unreadable...
+        assert!(extract_marked_items_from_file(temp_file.path(), &config).is_err());
+
+        // ...with it, it is skipped quietly.
+        config.skip_binary = true;
+        let result = extract_marked_items_from_file(temp_file.path(), &config);
+        assert_eq!(result, Ok(Vec::new()));
+    }
+
     #[test]
     fn test_extract_marked_items_from_file_permission_denied() {
         init_logger();
         let config = MarkerConfig {
             markers: vec!["TODO".to_string()],
+            ..Default::default()
         };
 
         test_permission_denied_unix(&config);
@@ -1025,6 +2769,7 @@ fn some_function() {
 
         let config = MarkerConfig {
             markers: vec!["TODO".to_string(), "FIXME".to_string(), "HACK".to_string()],
+            ..Default::default()
         };
 
         let start = Instant::now();
@@ -1057,6 +2802,7 @@ fn some_function() {
 
         let config = MarkerConfig {
             markers: vec!["TODO".to_string()],
+            ..Default::default()
         };
         let result = extract_marked_items_from_file(temp_file.path(), &config)
             .expect("extract should succeed");
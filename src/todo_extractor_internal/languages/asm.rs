@@ -0,0 +1,79 @@
+// src/languages/asm.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/asm.pest"]
+pub struct AsmParser;
+
+impl CommentParser for AsmParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::asm_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod asm_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_asm_semicolon_comments() {
+        init_logger();
+        let src = r#"; TODO: move to the data section
+section .text
+global _start
+_start:
+    mov eax, 1      ;; FIXME: use the syscall constant
+    cmp al, ';'
+    int 0x80
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("start.asm"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "move to the data section");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "use the syscall constant");
+    }
+
+    #[test]
+    fn test_asm_hash_comments() {
+        init_logger();
+        let src = r#"# TODO: support 32-bit targets
+    .globl main
+main:
+    movq $60, %rax   # FIXME: read the exit code
+    syscall
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("main.s"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "support 32-bit targets");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "read the exit code");
+    }
+
+    #[test]
+    fn test_asm_ignore_strings_and_immediates() {
+        let src = r##"msg: .ascii "# TODO: in a string; still a string"
+    mov r0, #4
+    db 'a;b', `c#d`
+"##;
+        let comments = AsmParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
@@ -0,0 +1,56 @@
+// src/languages/awk.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/awk.pest"]
+pub struct AwkParser;
+
+impl CommentParser for AwkParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::awk_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod awk_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_awk_line_comment() {
+        init_logger();
+        let src = r#"#!/usr/bin/awk -f
+# TODO: accept a custom separator
+BEGIN { FS = ":" }
+{ total += $3 / 2 }  # FIXME: rounding
+END { print total }
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("sum.awk"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "accept a custom separator");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "rounding");
+    }
+
+    #[test]
+    fn test_awk_ignore_hash_in_strings_and_regexes() {
+        let src = r##"/^# TODO: header/ { next }
+$0 ~ /a#b/ { print "# FIXME: not a comment" }
+!/[#\/]/ { n++ }
+"##;
+        let comments = AwkParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
@@ -0,0 +1,56 @@
+// src/languages/capnp.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/capnp.pest"]
+pub struct CapnpParser;
+
+impl CommentParser for CapnpParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::capnp_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod capnp_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_capnp_line_comment() {
+        init_logger();
+        let src = r#"@0xdbb9ad1f14bf0b36;
+
+# TODO: version the schema
+struct Person {
+  name @0 :Text;  # FIXME: split into first and last name
+  email @1 :Text;
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("person.capnp"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "version the schema");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "split into first and last name");
+    }
+
+    #[test]
+    fn test_capnp_ignore_hash_in_strings() {
+        let src = r##"const greeting :Text = "# TODO: not a comment \" # still not";
+"##;
+        let comments = CapnpParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
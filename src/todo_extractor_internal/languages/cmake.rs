@@ -0,0 +1,83 @@
+// src/languages/cmake.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/cmake.pest"]
+pub struct CmakeParser;
+
+impl CommentParser for CmakeParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::cmake_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod cmake_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cmakelists_line_comment() {
+        init_logger();
+        let src = r#"cmake_minimum_required(VERSION 3.20)
+# TODO: drop the C++14 fallback
+project(demo CXX) # TODO: set a version
+"#;
+        let todos = test_extract_marked_items(Path::new("CMakeLists.txt"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "drop the C++14 fallback");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "set a version");
+    }
+
+    #[test]
+    fn test_cmake_module_ignores_arguments() {
+        init_logger();
+        let src = r##"message(STATUS "# TODO: not a comment")
+set(SCRIPT [[
+  # TODO: still a bracket argument
+]])
+set(RAW [=[ ]] # TODO: nor this ]=])
+# TODO: real one
+"##;
+        let todos = test_extract_marked_items(Path::new("cmake/Deps.cmake"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 6);
+        assert_eq!(todos[0].message, "real one");
+    }
+
+    #[test]
+    fn test_cmake_bracket_comment() {
+        init_logger();
+        let src = r#"#[[
+TODO: split the install rules
+  into their own module
+]]
+#[==[ TODO: nested ]] brackets ]==]
+add_subdirectory(src)
+"#;
+        let todos = test_extract_marked_items(Path::new("CMakeLists.txt"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(
+            todos[0].message,
+            "split the install rules into their own module"
+        );
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "nested ]] brackets");
+    }
+}
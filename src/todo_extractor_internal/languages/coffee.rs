@@ -0,0 +1,75 @@
+// src/languages/coffee.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/coffee.pest"]
+pub struct CoffeeParser;
+
+impl CommentParser for CoffeeParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::coffee_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod coffee_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_coffee_line_comment() {
+        init_logger();
+        let src = r#"square = (x) ->
+  # TODO: handle non-numeric input
+  x * x # TODO: memoize
+"#;
+        let todos = test_extract_marked_items(Path::new("math.coffee"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "handle non-numeric input");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "memoize");
+    }
+
+    #[test]
+    fn test_coffee_block_comment() {
+        init_logger();
+        let src = r#"###
+TODO: split this module
+  once the router lands
+###
+class App
+"#;
+        let todos = test_extract_marked_items(Path::new("app.coffee"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "split this module once the router lands");
+    }
+
+    #[test]
+    fn test_coffee_ignore_hash_in_strings() {
+        init_logger();
+        let src = r##"greeting = "Hello #{name} # TODO: not a comment"
+nested = "#{if ok then "# TODO: nope" else ''}"
+single = '# TODO: also not a comment'
+doc = """
+# TODO: inside a heredoc
+"""
+"##;
+        let todos = test_extract_marked_items(Path::new("strings.coffee"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
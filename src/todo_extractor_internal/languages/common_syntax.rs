@@ -5,6 +5,11 @@
 /// Removes common language-specific comment markers from the beginning and end of the text.
 /// It only removes the marker characters (and an optional extra whitespace immediately following
 /// a leading marker or preceding a trailing marker) without trimming all other whitespace.
+///
+/// Only delimiters that can't be mistaken for comment text in other languages are listed
+/// here. Grammars whose delimiters aren't (`%`, `(* *)`, `{- -}`, template tags, ...) match
+/// them in silent `_{ }` rules, usually together with the whitespace after a block opener,
+/// so only the body is captured and it starts on the line of its first word.
 pub fn strip_markers(text: &str) -> String {
     // Work on a mutable owned string.
     let mut result = text.to_string();
@@ -69,6 +74,10 @@ mod tests {
 
         let input_html = "<!-- Important comment -->";
         assert_eq!(strip_markers(input_html), "Important comment");
+
+        // "*)" only closes OCaml/Pascal comments, which their grammars handle.
+        let input_glob = "// TODO: support glob (a*)";
+        assert_eq!(strip_markers(input_glob), "TODO: support glob (a*)");
     }
 
     #[test]
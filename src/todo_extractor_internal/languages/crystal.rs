@@ -0,0 +1,75 @@
+// src/languages/crystal.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/crystal.pest"]
+pub struct CrystalParser;
+
+impl CommentParser for CrystalParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::crystal_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod crystal_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_crystal_line_comment() {
+        init_logger();
+        let src = r#"# TODO: x
+def square(x : Int32) : Int32
+  x * x # TODO: check for overflow
+end
+"#;
+        let todos = test_extract_marked_items(Path::new("math.cr"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "x");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "check for overflow");
+    }
+
+    #[test]
+    fn test_crystal_ignores_strings_and_interpolation() {
+        init_logger();
+        let src = r##"puts "# TODO: not a comment"
+puts "total: #{count} # TODO: still a string"
+puts "nested #{"# TODO: inner"} done"
+sep = '#'
+# TODO: real one
+"##;
+        let todos = test_extract_marked_items(Path::new("app.cr"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 5);
+        assert_eq!(todos[0].message, "real one");
+    }
+
+    #[test]
+    fn test_crystal_multiline_comment() {
+        init_logger();
+        let src = r#"# TODO: split this class
+#   once the router lands
+class App
+end
+"#;
+        let todos = test_extract_marked_items(Path::new("app.cr"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "split this class once the router lands");
+    }
+}
@@ -0,0 +1,90 @@
+// src/languages/d.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/d.pest"]
+pub struct DParser;
+
+impl CommentParser for DParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::d_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod d_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_d_line_comment() {
+        init_logger();
+        let src = r#"void main() {
+    // TODO: parse arguments
+    writeln("hi"); // TODO: localize
+}
+"#;
+        let todos = test_extract_marked_items(Path::new("app.d"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "parse arguments");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "localize");
+    }
+
+    #[test]
+    fn test_d_block_comment() {
+        init_logger();
+        let src = r#"/* TODO: replace with a range */
+int[] squares;
+"#;
+        let todos = test_extract_marked_items(Path::new("squares.d"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "replace with a range");
+    }
+
+    #[test]
+    fn test_d_nested_comment() {
+        init_logger();
+        let src = r#"/+
+TODO: drop the GC allocation
+  /+ old: auto buf = new ubyte[64]; +/
++/
+void run() {}
+"#;
+        let todos = test_extract_marked_items(Path::new("run.d"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(
+            todos[0].message,
+            "drop the GC allocation /+ old: auto buf = new ubyte[64]; +/"
+        );
+    }
+
+    #[test]
+    fn test_d_ignore_comment_markers_in_strings() {
+        init_logger();
+        let src = r#"auto a = "// TODO: not a comment";
+auto b = r"C:\path /* TODO: nope */";
+auto c = `/+ TODO: wysiwyg +/`;
+auto d = "escaped \" // TODO: still a string";
+char e = '"';
+"#;
+        let todos = test_extract_marked_items(Path::new("strings.d"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
@@ -27,6 +27,7 @@ mod dockerfile_tests {
 FROM alpine"#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
 
         // TODO now in the tests i need to actually create the file instead of passing a fake path and a content
@@ -53,6 +54,7 @@ RUN apk add --no-cache \
 WORKDIR /app"#;
         let config = MarkerConfig {
             markers: vec!["TODO".to_string()],
+            ..Default::default()
         };
 
         let todos = test_extract_marked_items(Path::new("Dockerfile"), src, &config);
@@ -92,6 +94,7 @@ USER root
 CMD ["./app"]"#;
         let config = MarkerConfig {
             markers: vec!["TODO".to_string(), "FIXME".to_string(), "HACK".to_string()],
+            ..Default::default()
         };
 
         let todos = test_extract_marked_items(Path::new("Dockerfile"), src, &config);
@@ -151,6 +154,7 @@ RUN npm install && \
 EXPOSE 3000"#;
         let config = MarkerConfig {
             markers: vec!["TODO".to_string(), "FIXME".to_string()],
+            ..Default::default()
         };
 
         let todos = test_extract_marked_items(Path::new("Dockerfile"), src, &config);
@@ -0,0 +1,97 @@
+// src/languages/elm.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/elm.pest"]
+pub struct ElmParser;
+
+impl CommentParser for ElmParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::elm_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod elm_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_elm_line_comment() {
+        init_logger();
+        let src = r#"module Main exposing (main)
+
+-- TODO: read the greeting from flags
+main =
+    text "Hello" -- FIXME: translate
+"#;
+        let todos = test_extract_marked_items(Path::new("Main.elm"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "read the greeting from flags");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "translate");
+    }
+
+    #[test]
+    fn test_elm_nested_block_comment() {
+        init_logger();
+        let src = r#"{-| TODO: document the update loop
+    {- an inner note -}
+    before the 1.0 release
+-}
+update msg model = model
+"#;
+        let todos = test_extract_marked_items(Path::new("Update.elm"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(
+            todos[0].message,
+            "document the update loop {- an inner note -} before the 1.0 release"
+        );
+    }
+
+    #[test]
+    fn test_purescript_comments() {
+        init_logger();
+        let src = r#"module Main where
+
+-- | TODO: export fewer names
+{- FIXME: switch to
+     Aff once it lands -}
+main :: Effect Unit
+main = log "done"
+"#;
+        let todos = test_extract_marked_items(Path::new("Main.purs"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "export fewer names");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "switch to Aff once it lands");
+    }
+
+    #[test]
+    fn test_elm_ignore_comments_in_literals() {
+        let src = r#"sep = "-- TODO: not a comment"
+block = """{- FIXME: nor this -}"""
+dash = '-'
+x' = x
+"#;
+        let comments = ElmParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
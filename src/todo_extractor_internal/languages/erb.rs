@@ -0,0 +1,76 @@
+// src/languages/erb.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/erb.pest"]
+pub struct ErbParser;
+
+impl CommentParser for ErbParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::erb_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod erb_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_erb_comment_tag() {
+        init_logger();
+        let src = r#"<h1><%= @post.title %></h1>
+<%# TODO: paginate comments %>
+<% @post.comments.each do |c| %>
+  <%# FIXME: escape the author name -%>
+  <p><%= c.author %></p>
+<% end %>
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("show.html.erb"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "paginate comments");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "escape the author name");
+    }
+
+    #[test]
+    fn test_erb_html_comment() {
+        init_logger();
+        let src = "<!-- TODO: add a footer -->\n<%= render 'nav' %>\n";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("layout.erb"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "add a footer");
+    }
+
+    #[test]
+    fn test_erb_ignore_comment_openers_in_code_tags() {
+        init_logger();
+        let src = r#"<%= "<%# TODO: not a comment" %>
+<% label = "<!-- TODO: not one either -->" %>
+<%# TODO: real one %>
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("form.html.erb"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
@@ -0,0 +1,57 @@
+// src/languages/erlang.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/erlang.pest"]
+pub struct ErlangParser;
+
+impl CommentParser for ErlangParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::erlang_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod erlang_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_erlang_line_comments() {
+        init_logger();
+        let src = r#"-module(counter).
+%% TODO: export a reset/1
+-export([start/0]).
+
+start() ->
+    spawn(fun() -> loop(0) end). % FIXME: link to the caller
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("counter.erl"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "export a reset/1");
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "link to the caller");
+    }
+
+    #[test]
+    fn test_erlang_ignore_percent_in_literals() {
+        let src = r#"format(X) -> io_lib:format("~p% TODO: not a comment", [X]).
+percent() -> $%.
+quote() -> {$", '% TODO: quoted atom'}.
+"#;
+        let comments = ErlangParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
@@ -0,0 +1,58 @@
+// src/languages/fortran.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/fortran.pest"]
+pub struct FortranParser;
+
+impl CommentParser for FortranParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::fortran_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod fortran_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_fortran_line_and_trailing_comments() {
+        init_logger();
+        let src = r#"program solver
+  ! TODO: read the grid size from input
+  integer :: n = 64 ! FIXME: must be a power of two
+end program solver
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("solver.f90"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "read the grid size from input");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "must be a power of two");
+    }
+
+    #[test]
+    fn test_fortran_ignore_bang_in_strings() {
+        init_logger();
+        let src = r#"print *, "Done! TODO: not a comment"
+print *, 'it''s ! TODO: still a string'
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("report.f95"), src, &config);
+        assert_eq!(todos.len(), 0);
+    }
+}
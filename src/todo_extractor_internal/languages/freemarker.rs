@@ -0,0 +1,79 @@
+// src/languages/freemarker.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/freemarker.pest"]
+pub struct FreemarkerParser;
+
+impl CommentParser for FreemarkerParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::freemarker_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod freemarker_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_freemarker_comment() {
+        init_logger();
+        let src = r#"<ul>
+<#-- TODO: sort the users -->
+<#list users as user><li>${user.name}</li></#list>
+</ul>
+"#;
+        let todos = test_extract_marked_items(Path::new("users.ftl"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "sort the users");
+    }
+
+    #[test]
+    fn test_freemarker_multiline_and_square_comments() {
+        init_logger();
+        let src = r#"[#ftl]
+[#-- TODO: switch to angle brackets --]
+<#--
+  TODO: paginate the list
+    once the API supports it
+-->
+"#;
+        let todos = test_extract_marked_items(Path::new("list.ftl"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "switch to angle brackets");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(
+            todos[1].message,
+            "paginate the list once the API supports it"
+        );
+    }
+
+    #[test]
+    fn test_freemarker_ignores_noparse() {
+        init_logger();
+        let src = r#"<#noparse><#-- TODO: output as-is --></#noparse>
+TODO: plain text
+<#-- TODO: real one -->
+"#;
+        let todos = test_extract_marked_items(Path::new("page.ftl"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
@@ -0,0 +1,63 @@
+// src/languages/gdscript.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/gdscript.pest"]
+pub struct GdscriptParser;
+
+impl CommentParser for GdscriptParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::gdscript_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod gdscript_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_gdscript_line_comment() {
+        init_logger();
+        let src = r#"extends CharacterBody2D
+
+# TODO: read the speed from the project settings
+@export var speed := 300.0
+
+func _physics_process(delta):
+	var sprite = $Body/Sprite2D  # FIXME: cache in _ready
+	move_and_slide()
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("player.gd"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "read the speed from the project settings");
+        assert_eq!(todos[1].line_number, 7);
+        assert_eq!(todos[1].message, "cache in _ready");
+    }
+
+    #[test]
+    fn test_gdscript_ignore_hash_in_strings_and_node_paths() {
+        let src = r##"var color = "#ff0000"
+var label = $"HUD/Score #1"
+var name = &"# TODO: a StringName"
+var help = """
+# TODO: inside a multi-line string
+"""
+var quoted = 'it\'s # not a comment'
+"##;
+        let comments = GdscriptParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
@@ -0,0 +1,67 @@
+// src/languages/gherkin.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/gherkin.pest"]
+pub struct GherkinParser;
+
+impl CommentParser for GherkinParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::gherkin_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod gherkin_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_gherkin_line_comment() {
+        init_logger();
+        let src = r#"Feature: Checkout
+  # TODO: add scenario for expired cards
+
+  Scenario: Pay with a saved card
+    Given a cart with 2 items
+    # FIXME: the total ignores shipping
+    Then the total is 20
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("checkout.feature"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "add scenario for expired cards");
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "the total ignores shipping");
+    }
+
+    #[test]
+    fn test_gherkin_ignore_hash_in_doc_strings_and_tables() {
+        let src = r#"Feature: Issues
+  Scenario Outline: Close an issue
+    Given issue #<id> is open
+    When I post the comment
+      """
+      # TODO: inside a doc string
+      """
+    Then it is closed
+
+    Examples:
+      | id | title          |
+      | #1 | # TODO: a cell |
+"#;
+        let comments = GherkinParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
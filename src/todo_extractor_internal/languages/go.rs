@@ -34,6 +34,7 @@ func main() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("main.go"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -53,6 +54,7 @@ func process() error {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("process.go"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -77,6 +79,7 @@ func foo() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("example.go"), src, &config);
         assert_eq!(todos.len(), 3);
@@ -96,6 +99,7 @@ const raw = `TODO: Raw string should be ignored`
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("strings.go"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -116,6 +120,7 @@ import "fmt"
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("main.go"), src, &config);
         assert_eq!(todos.len(), 2);
@@ -156,6 +161,7 @@ func authenticate() error { return nil }
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("auth.go"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -178,6 +184,7 @@ func main() {}
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("nested.go"), src, &config);
         // The parser should find at least one TODO
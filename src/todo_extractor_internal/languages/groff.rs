@@ -0,0 +1,54 @@
+// src/languages/groff.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/groff.pest"]
+pub struct GroffParser;
+
+impl CommentParser for GroffParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::groff_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod groff_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_groff_comment_lines() {
+        init_logger();
+        let src = r#".TH TOOL 1
+.\" TODO: document flag
+.SH OPTIONS
+.TP
+\fB\-v\fR  \" FIXME: mention the short form
+Print the version.
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("tool.1"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "document flag");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "mention the short form");
+    }
+
+    #[test]
+    fn test_groff_ignore_escaped_backslash() {
+        let src = ".B C:\\\\\" TODO: not a comment\nTODO: plain text\n";
+        let comments = GroffParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
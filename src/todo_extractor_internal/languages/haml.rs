@@ -0,0 +1,65 @@
+// src/languages/haml.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/haml.pest"]
+pub struct HamlParser;
+
+impl CommentParser for HamlParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::haml_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod haml_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_haml_silent_comment() {
+        init_logger();
+        let src = r#"%h1= @post.title
+-# TODO: paginate comments
+- @post.comments.each do |c|
+  -# FIXME: escape the author name
+  %p= c.author
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("show.html.haml"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "paginate comments");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "escape the author name");
+    }
+
+    #[test]
+    fn test_slim_comments() {
+        init_logger();
+        let src = r#"h1 = @post.title
+/ TODO: paginate comments
+p.note a/b TODO: not a comment
+/! TODO: visible in the HTML
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("show.slim"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "paginate comments");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "visible in the HTML");
+    }
+}
@@ -0,0 +1,78 @@
+// src/languages/handlebars.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/handlebars.pest"]
+pub struct HandlebarsParser;
+
+impl CommentParser for HandlebarsParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::handlebars_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod handlebars_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_handlebars_short_comment() {
+        init_logger();
+        let src = r#"<h1>{{title}}</h1>
+{{! TODO: localize the title }}
+{{~! TODO: trim me ~}}
+"#;
+        let todos = test_extract_marked_items(Path::new("page.hbs"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "localize the title");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "trim me");
+    }
+
+    #[test]
+    fn test_handlebars_long_comment() {
+        init_logger();
+        let src = r#"{{!--
+  TODO: drop the legacy {{helper}} call
+    after the upgrade
+--}}
+{{#each items}}<li>{{this}}</li>{{/each}}
+"#;
+        let todos = test_extract_marked_items(Path::new("list.hbs"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(
+            todos[0].message,
+            "drop the legacy {{helper}} call after the upgrade"
+        );
+    }
+
+    #[test]
+    fn test_mustache_ignores_expressions() {
+        init_logger();
+        let src = r#"{{greeting}} TODO: plain text
+{{{raw_html}}}
+{{> partial title="{{! TODO: not a comment }}"}}
+{{! TODO: real one }}
+"#;
+        let todos = test_extract_marked_items(Path::new("card.mustache"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 4);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
@@ -0,0 +1,79 @@
+// src/languages/jinja.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/jinja.pest"]
+pub struct JinjaParser;
+
+impl CommentParser for JinjaParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::jinja_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod jinja_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_jinja_comment() {
+        init_logger();
+        let src = r#"<ul>
+{# TODO: x #}
+{% for user in users %}<li>{{ user.name }}</li>{% endfor %}
+</ul>
+"#;
+        let todos = test_extract_marked_items(Path::new("users.html.j2"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "x");
+    }
+
+    #[test]
+    fn test_twig_multiline_comment() {
+        init_logger();
+        let src = r#"{% block body %}
+{#-
+  TODO: paginate the list
+    once the API supports it
+-#}
+{% endblock %}
+"#;
+        let todos = test_extract_marked_items(Path::new("list.twig"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(
+            todos[0].message,
+            "paginate the list once the API supports it"
+        );
+    }
+
+    #[test]
+    fn test_jinja_ignores_expressions_and_tags() {
+        init_logger();
+        let src = r##"{{ "{# TODO: not a comment #}" }}
+{% set note = "{# TODO: nor this #}" %}
+{% raw %}{# TODO: raw text #}{% endraw %}
+TODO: plain text
+{# TODO: real one #}
+"##;
+        let todos = test_extract_marked_items(Path::new("page.j2"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 5);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
@@ -34,6 +34,7 @@ function init() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("test.js"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -53,6 +54,7 @@ function init() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("test.js"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -77,6 +79,7 @@ function foo() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("test.js"), src, &config);
         assert_eq!(todos.len(), 3);
@@ -96,6 +99,7 @@ const template = `TODO: Or this ${variable}`;
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("test.js"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -114,6 +118,7 @@ const Component = () => {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("component.jsx"), src, &config);
         assert_eq!(todos.len(), 2);
@@ -151,6 +156,7 @@ function authenticate() {}
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("auth.js"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -159,4 +165,20 @@ function authenticate() {}
             "Implement authentication Add JWT token validation Handle token expiration"
         );
     }
+
+    #[test]
+    fn test_js_block_comment_keeps_leading_bang() {
+        init_logger();
+        let src = r#"
+/* TODO: keep the override
+   !important must stay */
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("style.js"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "keep the override !important must stay");
+    }
 }
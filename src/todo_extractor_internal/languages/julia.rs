@@ -0,0 +1,77 @@
+// src/languages/julia.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/julia.pest"]
+pub struct JuliaParser;
+
+impl CommentParser for JuliaParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::julia_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod julia_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_julia_line_comment() {
+        init_logger();
+        let src = r#"function step!(u)
+    # TODO: use a preallocated buffer
+    u .+= 1 # TODO: make the increment configurable
+end
+"#;
+        let todos = test_extract_marked_items(Path::new("model.jl"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "use a preallocated buffer");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "make the increment configurable");
+    }
+
+    #[test]
+    fn test_julia_nested_block_comment() {
+        init_logger();
+        let src = r#"#= TODO: switch to DifferentialEquations.jl
+     once the API settles #= nested =# =#
+solve(p)
+"#;
+        let todos = test_extract_marked_items(Path::new("solve.jl"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(
+            todos[0].message,
+            "switch to DifferentialEquations.jl once the API settles #= nested =#"
+        );
+    }
+
+    #[test]
+    fn test_julia_ignore_hash_in_strings() {
+        init_logger();
+        let src = r##"println("# TODO: not a comment")
+println("total: $(count("# TODO: nope")) items")
+c = '#'
+s = """
+# TODO: inside a triple-quoted string
+"""
+"##;
+        let todos = test_extract_marked_items(Path::new("io.jl"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
@@ -0,0 +1,77 @@
+// src/languages/liquid.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/liquid.pest"]
+pub struct LiquidParser;
+
+impl CommentParser for LiquidParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::liquid_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod liquid_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_liquid_comment_block() {
+        init_logger();
+        let src = r#"<h1>{{ product.title }}</h1>
+{%- comment -%}
+  TODO: show the sale price
+    when the product is discounted
+{%- endcomment -%}
+{% comment %}TODO: add reviews{% endcomment %}
+"#;
+        let todos = test_extract_marked_items(Path::new("product.liquid"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(
+            todos[0].message,
+            "show the sale price when the product is discounted"
+        );
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "add reviews");
+    }
+
+    #[test]
+    fn test_liquid_inline_comment() {
+        init_logger();
+        let src = "{% # TODO: cache this loop %}\n{% for item in cart.items %}{{ item.title }}{% endfor %}\n";
+        let todos = test_extract_marked_items(Path::new("cart.liquid"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "cache this loop");
+    }
+
+    #[test]
+    fn test_liquid_ignores_output_and_tags() {
+        init_logger();
+        let src = r#"{{ "{% comment %}TODO: not a comment{% endcomment %}" }}
+{% assign note = "TODO: nor this %}" %}
+{% raw %}{% comment %}TODO: raw text{% endcomment %}{% endraw %}
+TODO: plain text
+{% comment %}TODO: real one{% endcomment %}
+"#;
+        let todos = test_extract_marked_items(Path::new("page.liquid"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 5);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
@@ -28,6 +28,7 @@ mod markdown_tests {
         let src = "<!-- TODO: document -->\ntext";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("README.md"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -0,0 +1,95 @@
+// src/languages/matlab.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/matlab.pest"]
+pub struct MatlabParser;
+
+impl CommentParser for MatlabParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::matlab_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod matlab_tests {
+    use super::MatlabParser;
+    use crate::test_utils::init_logger;
+    use crate::todo_extractor_internal::aggregator::{
+        extract_marked_items_with_parser, MarkedItem, MarkerConfig,
+    };
+    use crate::todo_extractor_internal::languages::common::CommentParser;
+    use std::path::Path;
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    // `.m` is routed to Objective-C, so parse with the MATLAB grammar
+    // directly, as `--parser-override m=matlab` does.
+    fn extract(src: &str) -> Vec<MarkedItem> {
+        extract_marked_items_with_parser(
+            Path::new("solver.m"),
+            src,
+            MatlabParser::parse_comments,
+            &todo_config(),
+        )
+    }
+
+    #[test]
+    fn test_matlab_line_comment() {
+        init_logger();
+        let src = r#"function y = solve(A, b)
+% TODO: check the condition number
+y = A \ b; % TODO: use a sparse solver
+end
+"#;
+        let todos = extract(src);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "check the condition number");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "use a sparse solver");
+    }
+
+    #[test]
+    fn test_matlab_block_comment() {
+        init_logger();
+        let src = r#"%{
+TODO: vectorize this loop
+  once inputs are validated
+%}
+for k = 1:n
+end
+"#;
+        let todos = extract(src);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(
+            todos[0].message,
+            "vectorize this loop once inputs are validated"
+        );
+    }
+
+    #[test]
+    fn test_matlab_ignores_strings_and_format_specifiers() {
+        init_logger();
+        let src = r#"fprintf('% TODO: not a comment %d\n', x);
+msg = "100% TODO: nor this";
+s = sprintf('it''s %s', name);
+z = x'; w = (a+b)'; % TODO: real one
+r = a%TODO: code, not a comment
+"#;
+        let todos = extract(src);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 4);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
@@ -0,0 +1,46 @@
+// src/languages/mermaid.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/mermaid.pest"]
+pub struct MermaidParser;
+
+impl CommentParser for MermaidParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::mermaid_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod mermaid_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_mermaid_comments() {
+        init_logger();
+        let src = r#"%%{init: {"theme": "dark", "note": "TODO: not a comment"}}%%
+flowchart LR
+    %% TODO: add the retry edge
+    A[Start] --> B{50%% TODO: label}
+    B --> C
+%% TODO: pick colors
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("flow.mmd"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "add the retry edge");
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "pick colors");
+    }
+}
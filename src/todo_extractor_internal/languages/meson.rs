@@ -0,0 +1,57 @@
+// src/languages/meson.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/meson.pest"]
+pub struct MesonParser;
+
+impl CommentParser for MesonParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::meson_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod meson_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_meson_line_comment() {
+        init_logger();
+        let src = r#"project('demo', 'c', version : '0.1')
+# TODO: drop the C++ fallback
+executable('demo', 'main.c')  # FIXME: link against libm
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("meson.build"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "drop the C++ fallback");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "link against libm");
+    }
+
+    #[test]
+    fn test_meson_ignore_hash_in_strings() {
+        let src = r#"conf.set('HEADER', '#include <stdio.h>')
+msg = f'# TODO: @0@ in a format string'
+desc = '''
+# TODO: inside a multi-line string
+'''
+esc = 'it\'s # not a comment'
+"#;
+        let comments = MesonParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
@@ -1,13 +1,54 @@
+pub mod asm;
+pub mod awk;
+pub mod capnp;
+pub mod cmake;
+pub mod coffee;
 pub mod common;
 pub mod common_syntax;
+pub mod crystal;
+pub mod d;
 pub mod dockerfile;
+pub mod elm;
+pub mod erb;
+pub mod erlang;
+pub mod fortran;
+pub mod freemarker;
+pub mod gdscript;
+pub mod gherkin;
 pub mod go;
+pub mod groff;
+pub mod haml;
+pub mod handlebars;
+pub mod jinja;
 pub mod js;
+pub mod julia;
+pub mod liquid;
 pub mod markdown;
+pub mod matlab;
+pub mod mermaid;
+pub mod meson;
+pub mod nim;
+pub mod nushell;
+pub mod ocaml;
+pub mod pascal;
+pub mod plantuml;
+pub mod pug;
 pub mod python;
+pub mod rego;
+pub mod rst;
 pub mod rust;
+pub mod scheme;
 pub mod shell;
+pub mod smalltalk;
+pub mod solidity;
 pub mod sql;
+pub mod systemd;
+pub mod tcl;
+pub mod thrift;
 pub mod toml;
+pub mod velocity;
+pub mod vim;
+pub mod wat;
+pub mod xml;
 pub mod yaml;
 // pub mod ts;
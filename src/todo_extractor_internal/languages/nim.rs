@@ -0,0 +1,92 @@
+// src/languages/nim.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/nim.pest"]
+pub struct NimParser;
+
+impl CommentParser for NimParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::nim_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod nim_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_nim_line_comment() {
+        init_logger();
+        let src = r#"proc greet(name: string) =
+  # TODO: validate name
+  echo "Hello ", name # TODO: localize
+"#;
+        let todos = test_extract_marked_items(Path::new("greet.nim"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "validate name");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "localize");
+    }
+
+    #[test]
+    fn test_nim_doc_comment() {
+        init_logger();
+        let src = r#"proc area(r: float): float =
+  ## TODO: document the units
+  PI * r * r
+"#;
+        let todos = test_extract_marked_items(Path::new("area.nim"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "document the units");
+    }
+
+    #[test]
+    fn test_nim_nested_block_comment() {
+        init_logger();
+        let src = r#"#[
+TODO: switch to async sockets
+  once asyncnet is stable #[ old: import net ]#
+]#
+import asyncnet
+"#;
+        let todos = test_extract_marked_items(Path::new("server.nim"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(
+            todos[0].message,
+            "switch to async sockets once asyncnet is stable #[ old: import net ]#"
+        );
+    }
+
+    #[test]
+    fn test_nim_ignore_hash_in_strings() {
+        init_logger();
+        let src = r##"let a = "# TODO: not a comment"
+let b = &"{a} # TODO: interpolated"
+let c = r"C:\ # TODO: raw"
+let d = '#'
+let e = """
+# TODO: inside a triple-quoted string
+"""
+"##;
+        let todos = test_extract_marked_items(Path::new("strings.nim"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
@@ -0,0 +1,68 @@
+// src/languages/nushell.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/nushell.pest"]
+pub struct NushellParser;
+
+impl CommentParser for NushellParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::nushell_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod nushell_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_nushell_comments() {
+        init_logger();
+        let src = r##"# TODO: add a --verbose flag
+def greet [name: string] {
+    let msg = $"hello (["#" $name] | str join) # TODO: not a comment"
+    print "# TODO: nor this" # TODO: trailing one
+    let url = 'http://example.com/#anchor'
+    let raw = r#'it's # TODO: raw'#
+    let tag = foo#bar
+}
+"##;
+        let todos = test_extract_marked_items(Path::new("greet.nu"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "add a --verbose flag");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "trailing one");
+    }
+
+    #[test]
+    fn test_fish_comments() {
+        init_logger();
+        let src = r##"function greet # TODO: document
+    set -l msg "hi $(echo "#x") # TODO: not a comment"
+    echo a#b
+    # TODO: handle --help
+end
+"##;
+        let todos = test_extract_marked_items(Path::new("greet.fish"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "document");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "handle --help");
+    }
+}
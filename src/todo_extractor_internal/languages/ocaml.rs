@@ -0,0 +1,125 @@
+// src/languages/ocaml.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/ocaml.pest"]
+pub struct OcamlParser;
+
+impl CommentParser for OcamlParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::ocaml_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod ocaml_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_ocaml_single_line_comment() {
+        init_logger();
+        let src = r#"(* TODO: x *)
+let () = print_endline "hello""#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("main.ml"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "x");
+    }
+
+    #[test]
+    fn test_ocaml_multiline_comment() {
+        init_logger();
+        let src = r#"
+(* TODO: Refactor the lexer
+   to stream tokens lazily *)
+let lex s = s
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("lexer.mli"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(
+            todos[0].message,
+            "Refactor the lexer to stream tokens lazily"
+        );
+    }
+
+    #[test]
+    fn test_ocaml_single_line_doc_comment() {
+        init_logger();
+        let src = r#"(** TODO: document the return value *)
+val parse : string -> t
+(**)
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("parser.mli"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "document the return value");
+    }
+
+    #[test]
+    fn test_ocaml_multiline_doc_comment() {
+        init_logger();
+        let src = r#"
+(** TODO: Explain the error cases
+    of [parse] *)
+val parse : string -> t
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("parser.ml"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "Explain the error cases of [parse]");
+    }
+
+    #[test]
+    fn test_ocaml_nested_comment_is_one_token() {
+        let src = r#"
+(* outer (* inner *) still outer *)
+let x = 1
+"#;
+        let comments = OcamlParser::parse_comments(src);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].text, "outer (* inner *) still outer");
+    }
+
+    #[test]
+    fn test_ocaml_ignore_comment_opener_in_string() {
+        init_logger();
+        let src = r#"
+let s = "(* TODO: not a comment *)"
+let c = '"'
+(* TODO: real one *)
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("strings.ml"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 4);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
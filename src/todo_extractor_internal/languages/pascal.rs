@@ -0,0 +1,81 @@
+// src/languages/pascal.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/pascal.pest"]
+pub struct PascalParser;
+
+impl CommentParser for PascalParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::pascal_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod pascal_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pascal_line_comment() {
+        init_logger();
+        let src = "begin\n  // TODO: validate input\n  WriteLn('hi');\nend.\n";
+        let todos = test_extract_marked_items(Path::new("main.pas"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "validate input");
+    }
+
+    #[test]
+    fn test_pascal_paren_star_comment() {
+        init_logger();
+        let src = r#"(* TODO: replace the bubble sort
+     with QuickSort *)
+procedure Sort;
+"#;
+        let todos = test_extract_marked_items(Path::new("sort.pas"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "replace the bubble sort with QuickSort");
+    }
+
+    #[test]
+    fn test_pascal_brace_comment() {
+        init_logger();
+        let src = r#"program Demo;
+{ TODO: read settings
+  from the ini file }
+begin
+end.
+"#;
+        let todos = test_extract_marked_items(Path::new("Demo.dpr"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "read settings from the ini file");
+    }
+
+    #[test]
+    fn test_pascal_ignore_directives_and_strings() {
+        init_logger();
+        let src = r#"{$IFDEF TODO: not a comment}
+(*$R+ TODO: not a comment either*)
+s := '{ TODO: in a string } it''s (* TODO: too *)';
+{$ENDIF}
+"#;
+        let todos = test_extract_marked_items(Path::new("unit1.pas"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
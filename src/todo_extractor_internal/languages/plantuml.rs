@@ -0,0 +1,67 @@
+// src/languages/plantuml.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/plantuml.pest"]
+pub struct PlantUmlParser;
+
+impl CommentParser for PlantUmlParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::plantuml_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod plantuml_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plantuml_line_comment() {
+        init_logger();
+        let src = r#"@startuml
+' TODO: add the cache layer
+Alice -> Bob : it's ' TODO: not a comment
+  ' TODO: rename Bob
+@enduml
+"#;
+        let todos = test_extract_marked_items(Path::new("flow.puml"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "add the cache layer");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "rename Bob");
+    }
+
+    #[test]
+    fn test_plantuml_block_comment() {
+        init_logger();
+        let src = r#"@startuml
+/'
+  TODO: split the sequence
+    into two diagrams
+'/
+Alice -> Bob : hello /' TODO: inline block '/
+@enduml
+"#;
+        let todos = test_extract_marked_items(Path::new("flow.plantuml"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "split the sequence into two diagrams");
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "inline block");
+    }
+}
@@ -0,0 +1,72 @@
+// src/languages/pug.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/pug.pest"]
+pub struct PugParser;
+
+impl CommentParser for PugParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::pug_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod pug_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pug_line_comments() {
+        init_logger();
+        let src = r#"doctype html
+html
+  head
+    //- TODO: self-host the fonts
+    title Home
+  body
+    // TODO: drop once the banner ships
+    p Visit https://example.com // TODO: not a comment
+"#;
+        let todos = test_extract_marked_items(Path::new("index.pug"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 4);
+        assert_eq!(todos[0].message, "self-host the fonts");
+        assert_eq!(todos[1].line_number, 7);
+        assert_eq!(todos[1].message, "drop once the banner ships");
+    }
+
+    #[test]
+    fn test_pug_indented_block_continuation() {
+        init_logger();
+        let src = r#"ul
+  //- TODO: paginate
+    once the API returns a cursor
+  each item in items
+    li= item
+  //
+    TODO: move the footer
+      into a mixin
+  footer
+"#;
+        let todos = test_extract_marked_items(Path::new("list.jade"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "paginate once the API returns a cursor");
+        assert_eq!(todos[1].line_number, 7);
+        assert_eq!(todos[1].message, "move the footer into a mixin");
+    }
+}
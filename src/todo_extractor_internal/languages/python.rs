@@ -32,6 +32,7 @@ x = "TODO: not a comment"
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("test.py"), src, &config);
         println!("{todos:?}");
@@ -53,6 +54,7 @@ def f():
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("test.py"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -76,6 +78,7 @@ def f():
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.py"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -90,6 +93,7 @@ def f():
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("file.py"), src, &config);
         assert_eq!(todos.len(), 0);
@@ -111,6 +115,7 @@ def big_function():
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("multi_todos.py"), src, &config);
 
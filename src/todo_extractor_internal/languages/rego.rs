@@ -0,0 +1,62 @@
+// src/languages/rego.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/rego.pest"]
+pub struct RegoParser;
+
+impl CommentParser for RegoParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::rego_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod rego_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_rego_line_comment() {
+        init_logger();
+        let src = r#"package authz
+
+# TODO: tighten policy
+default allow := false
+
+allow if {
+    input.user.role == "admin" # FIXME: read roles from data
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("authz.rego"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "tighten policy");
+        assert_eq!(todos[1].line_number, 7);
+        assert_eq!(todos[1].message, "read roles from data");
+    }
+
+    #[test]
+    fn test_rego_ignore_hash_in_strings() {
+        init_logger();
+        let src = r#"msg := "channel #ops \" # TODO: not a comment"
+raw := `# TODO: not a comment either`
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("msg.rego"), src, &config);
+        assert_eq!(todos.len(), 0);
+    }
+}
@@ -0,0 +1,69 @@
+// src/languages/rst.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/rst.pest"]
+pub struct RstParser;
+
+impl CommentParser for RstParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::rst_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod rst_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rst_comment() {
+        init_logger();
+        let src = r#"Installation
+============
+
+.. TODO: expand section
+   with the Windows steps
+
+Run ``pip install tool``.
+
+..
+   TODO: add a screenshot
+"#;
+        let todos = test_extract_marked_items(Path::new("install.rst"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 4);
+        assert_eq!(todos[0].message, "expand section with the Windows steps");
+        assert_eq!(todos[1].line_number, 10);
+        assert_eq!(todos[1].message, "add a screenshot");
+    }
+
+    #[test]
+    fn test_rst_ignores_other_markup() {
+        let src = r#".. note:: TODO: not a comment
+.. py:function:: run()
+
+   TODO: nor this
+.. [1] TODO: a footnote
+.. _TODO: https://example.com
+.. |TODO| replace:: substitution
+TODO: plain text
+"#;
+        let comments = RstParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
@@ -35,6 +35,7 @@ fn main() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("example.rs"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -57,6 +58,7 @@ fn foo() {}
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("lib.rs"), src, &config);
 
@@ -133,6 +135,7 @@ fn foo() {
 "#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("large_file.rs"), src, &config);
 
@@ -161,4 +164,18 @@ fn foo() {
         assert_eq!(todos[3].line_number, 31);
         assert_eq!(todos[3].message, "fourth_todo");
     }
+
+    #[test]
+    fn test_rust_comment_ending_in_paren_star() {
+        init_logger();
+        let src = "// TODO: support glob (a*)\n/* FIXME: match (b*) */\nfn main() {}\n";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("glob.rs"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].message, "support glob (a*)");
+        assert_eq!(todos[1].message, "match (b*)");
+    }
 }
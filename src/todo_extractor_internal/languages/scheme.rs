@@ -0,0 +1,71 @@
+// src/languages/scheme.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/scheme.pest"]
+pub struct SchemeParser;
+
+impl CommentParser for SchemeParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::scheme_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod scheme_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scheme_line_comments() {
+        init_logger();
+        let src = r#"#lang racket
+;; TODO: memoize this
+(define (fib n)
+  (if (< n 2) n (+ (fib (- n 1)) (fib (- n 2))))) ; TODO: use a loop
+(display "; TODO: not a comment")
+(define semi #\;) (define quote #\")
+#;(TODO: commented-out code, not a comment)
+"#;
+        let todos = test_extract_marked_items(Path::new("fib.rkt"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "memoize this");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "use a loop");
+    }
+
+    #[test]
+    fn test_scheme_nested_block_comment() {
+        init_logger();
+        let src = r##"(define s "#| TODO: not a comment |#")
+#|
+  TODO: split this module #| see |# once
+    the tests pass
+|#
+(define x 1) #| TODO: inline block |#
+"##;
+        let todos = test_extract_marked_items(Path::new("util.scm"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(
+            todos[0].message,
+            "split this module #| see |# once the tests pass"
+        );
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "inline block");
+    }
+}
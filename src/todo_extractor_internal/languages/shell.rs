@@ -24,6 +24,7 @@ mod shell_tests {
 echo hello"#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("script.sh"), src, &config);
         assert_eq!(todos.len(), 1);
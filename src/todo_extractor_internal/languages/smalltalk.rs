@@ -0,0 +1,62 @@
+// src/languages/smalltalk.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/smalltalk.pest"]
+pub struct SmalltalkParser;
+
+impl CommentParser for SmalltalkParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::smalltalk_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod smalltalk_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_smalltalk_comment() {
+        init_logger();
+        let src = r#"printOn: aStream
+    "TODO: x"
+    aStream nextPutAll: 'TODO: y'.
+    "
+    TODO: cache the label
+      once the model settles
+    "
+    ^ self
+"#;
+        let todos = test_extract_marked_items(Path::new("Point.st"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "x");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "cache the label once the model settles");
+    }
+
+    #[test]
+    fn test_smalltalk_ignore_strings_and_chars() {
+        init_logger();
+        let src = r#"label := 'it''s "TODO: not a comment"'.
+quote := $".
+other := 'TODO: y'.
+"#;
+        let todos = test_extract_marked_items(Path::new("Label.st"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
@@ -0,0 +1,137 @@
+// src/languages/solidity.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/solidity.pest"]
+pub struct SolidityParser;
+
+impl CommentParser for SolidityParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::solidity_file, file_content)
+            .into_iter()
+            .map(|cl| CommentLine {
+                line_number: cl.line_number,
+                text: strip_natspec_tags(&cl.text),
+            })
+            .collect()
+    }
+}
+
+/// NatSpec tags that only introduce free text, so a marker can follow them.
+const NATSPEC_TEXT_TAGS: [&str; 2] = ["@notice", "@dev"];
+
+/// Drops a leading `@notice`/`@dev` tag from each line of a comment, so
+/// `/// @dev TODO: x` is matched like `/// TODO: x`. On tagged lines inside
+/// a `/** */` block (whose delimiters the grammar already dropped), the
+/// ` * ` decoration goes too.
+fn strip_natspec_tags(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let body = line.trim_start();
+            let (opener, rest) = ["///", "//", "*"]
+                .iter()
+                .find_map(|o| body.strip_prefix(o).map(|rest| (*o, rest)))
+                .unwrap_or(("", body));
+            let rest = rest.trim_start();
+            for tag in NATSPEC_TEXT_TAGS {
+                if let Some(after) = rest.strip_prefix(tag) {
+                    if after.is_empty() || after.starts_with(char::is_whitespace) {
+                        let indent = &line[..line.len() - body.len()];
+                        let opener = if opener == "*" { "" } else { opener };
+                        return format!("{indent}{opener} {}", after.trim_start());
+                    }
+                }
+            }
+            line.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod solidity_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_solidity_line_comment() {
+        init_logger();
+        let src = r#"contract Vault {
+    uint256 public total; // TODO: pack into a struct
+}
+"#;
+        let todos = test_extract_marked_items(Path::new("Vault.sol"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "pack into a struct");
+    }
+
+    #[test]
+    fn test_solidity_block_comment() {
+        init_logger();
+        let src = "/* TODO: audit reentrancy */\ncontract Vault {}\n";
+        let todos = test_extract_marked_items(Path::new("Vault.sol"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "audit reentrancy");
+    }
+
+    #[test]
+    fn test_solidity_natspec_comments() {
+        init_logger();
+        let src = r#"/// @notice TODO: document the fee model
+contract Vault {
+    /**
+     * @dev TODO: use SafeERC20
+     */
+    function withdraw() external {}
+}
+"#;
+        let todos = test_extract_marked_items(Path::new("Vault.sol"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "document the fee model");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "use SafeERC20");
+    }
+
+    #[test]
+    fn test_solidity_single_line_natspec_block() {
+        init_logger();
+        let src = r#"/** @notice TODO: explain the vault */
+contract Vault {
+    /** TODO: emit an event */
+    function deposit() external {}
+    /**/
+}
+"#;
+        let todos = test_extract_marked_items(Path::new("Vault.sol"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "explain the vault");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "emit an event");
+    }
+
+    #[test]
+    fn test_solidity_ignore_string_literals() {
+        init_logger();
+        let src = r#"string constant A = "// TODO: not a comment";
+string constant B = '/* TODO: not a comment either */';
+"#;
+        let todos = test_extract_marked_items(Path::new("Strings.sol"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
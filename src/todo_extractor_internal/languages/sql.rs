@@ -28,9 +28,27 @@ mod sql_tests {
         let src = "-- TODO: optimize\nSELECT 1;";
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("query.sql"), src, &config);
         assert_eq!(todos.len(), 1);
         assert_eq!(todos[0].message, "optimize");
     }
+
+    #[test]
+    fn test_sql_ignore_dashes_in_strings() {
+        init_logger();
+        let src = r#"SELECT '-- TODO: fake';
+SELECT * FROM notes WHERE note = 'it''s -- TODO: fake'; -- TODO: real
+SELECT "-- TODO: quoted identifier" FROM t;
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("query.sql"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "real");
+    }
 }
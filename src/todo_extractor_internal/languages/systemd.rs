@@ -0,0 +1,75 @@
+// src/languages/systemd.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/systemd.pest"]
+pub struct SystemdParser;
+
+impl CommentParser for SystemdParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::systemd_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod systemd_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_systemd_unit_comments() {
+        init_logger();
+        let src = r#"[Unit]
+Description=Backup job
+# TODO: order after the network is online
+
+[Service]
+  ; TODO: drop root privileges
+ExecStart=/usr/bin/backup
+"#;
+        let todos = test_extract_marked_items(Path::new("backup.service"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "order after the network is online");
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "drop root privileges");
+    }
+
+    #[test]
+    fn test_desktop_entry_comment() {
+        init_logger();
+        let src = r#"[Desktop Entry]
+# TODO: add a German translation
+Name=Editor
+Exec=editor %F
+"#;
+        let todos = test_extract_marked_items(Path::new("editor.desktop"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "add a German translation");
+    }
+
+    #[test]
+    fn test_systemd_ignores_hash_in_values() {
+        let src = r#"[Service]
+ExecStart=/bin/sh -c 'echo # TODO: not a comment; echo ; TODO: nor this'
+Environment=COLOR=#TODO
+"#;
+        let comments = SystemdParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
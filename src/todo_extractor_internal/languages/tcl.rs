@@ -0,0 +1,71 @@
+// src/languages/tcl.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/tcl.pest"]
+pub struct TclParser;
+
+impl CommentParser for TclParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::tcl_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod tcl_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_tcl_line_comment() {
+        init_logger();
+        let src = r#"# TODO: read the port from argv
+set port 8080
+proc serve {port} {
+    # FIXME: handle bind errors
+    socket -server accept $port
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("server.tcl"), src, &config);
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "read the port from argv");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "handle bind errors");
+    }
+
+    #[test]
+    fn test_tcl_comment_after_semicolon() {
+        init_logger();
+        let src = "set x 1 ;# TODO: use a namespace variable\n";
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("vars.tcl"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].message, "use a namespace variable");
+    }
+
+    #[test]
+    fn test_tcl_ignore_hash_in_strings_and_braces() {
+        let src = r#"set a "
+# TODO: inside a quoted word"
+set b {# TODO: inside a braced word}
+puts #notacomment
+"#;
+        let comments = TclParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
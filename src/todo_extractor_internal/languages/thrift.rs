@@ -0,0 +1,61 @@
+// src/languages/thrift.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/thrift.pest"]
+pub struct ThriftParser;
+
+impl CommentParser for ThriftParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::thrift_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod thrift_tests {
+    use super::*;
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_thrift_comment_styles() {
+        init_logger();
+        let src = r#"namespace rs tutorial
+
+// TODO: move to a shared include
+struct User {
+  1: string name,  # FIXME: enforce a maximum length
+  /* TODO: add the
+     creation date */
+  2: i64 id,
+}
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("user.thrift"), src, &config);
+        assert_eq!(todos.len(), 3);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "move to a shared include");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "enforce a maximum length");
+        assert_eq!(todos[2].line_number, 6);
+        assert_eq!(todos[2].message, "add the creation date");
+    }
+
+    #[test]
+    fn test_thrift_ignore_comments_in_strings() {
+        let src = r#"const string URL = "http://example.com/# TODO: not a comment"
+const string GLOB = '/* FIXME: not a comment */'
+"#;
+        let comments = ThriftParser::parse_comments(src);
+        assert!(comments.is_empty(), "unexpected comments: {comments:?}");
+    }
+}
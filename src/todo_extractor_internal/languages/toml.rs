@@ -25,6 +25,7 @@ mod toml_tests {
 key = 1"#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("config.toml"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -0,0 +1,76 @@
+// src/languages/velocity.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/velocity.pest"]
+pub struct VelocityParser;
+
+impl CommentParser for VelocityParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::velocity_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod velocity_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_velocity_line_comment() {
+        init_logger();
+        let src = r#"<ul>
+## TODO: sort the users
+#foreach( $user in $users )<li>$user.name</li>#end ## TODO: escape names
+</ul>
+"#;
+        let todos = test_extract_marked_items(Path::new("users.vm"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 2);
+        assert_eq!(todos[0].message, "sort the users");
+        assert_eq!(todos[1].line_number, 3);
+        assert_eq!(todos[1].message, "escape names");
+    }
+
+    #[test]
+    fn test_velocity_block_comment() {
+        init_logger();
+        let src = r#"#set( $title = "Home" )
+#*
+  TODO: read the title
+    from the page model
+*#
+<h1>$title</h1>
+"#;
+        let todos = test_extract_marked_items(Path::new("page.vm"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "read the title from the page model");
+    }
+
+    #[test]
+    fn test_velocity_ignores_unparsed_blocks() {
+        init_logger();
+        let src = r#"#[[## TODO: output as-is]]#
+TODO: plain text
+## TODO: real one
+"#;
+        let todos = test_extract_marked_items(Path::new("page.vm"), src, &todo_config());
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "real one");
+    }
+}
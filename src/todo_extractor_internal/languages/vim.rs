@@ -0,0 +1,61 @@
+// src/languages/vim.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/vim.pest"]
+pub struct VimParser;
+
+impl CommentParser for VimParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::vim_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod vim_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_vim_line_comment() {
+        init_logger();
+        let src = r#"" TODO: remap
+nnoremap <leader>w :w<CR>
+
+function! s:Trim()
+    " TODO: keep the cursor position
+    %s/\s\+$//e
+endfunction
+"#;
+        let todos = test_extract_marked_items(Path::new("init.vim"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "remap");
+        assert_eq!(todos[1].line_number, 5);
+        assert_eq!(todos[1].message, "keep the cursor position");
+    }
+
+    #[test]
+    fn test_vim_ignore_string_literals() {
+        init_logger();
+        let src = r#"echo "TODO: not a comment"
+let g:msg = "\" TODO: still a string"
+call setline(1, "TODO: nope")
+"#;
+        let todos = test_extract_marked_items(Path::new("plugin.vim"), src, &todo_config());
+        assert_eq!(todos.len(), 0);
+    }
+}
@@ -0,0 +1,72 @@
+// src/languages/wat.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/wat.pest"]
+pub struct WatParser;
+
+impl CommentParser for WatParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::wat_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod wat_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    fn todo_config() -> MarkerConfig {
+        MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_wat_line_comments() {
+        init_logger();
+        let src = r#";; TODO: export the memory
+(module
+  (data (i32.const 0) ";; TODO: not a comment")
+  (func $add (param i32 i32) (result i32) ;; TODO: check for overflow
+    local.get 0
+    local.get 1
+    i32.add))
+"#;
+        let todos = test_extract_marked_items(Path::new("add.wat"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 1);
+        assert_eq!(todos[0].message, "export the memory");
+        assert_eq!(todos[1].line_number, 4);
+        assert_eq!(todos[1].message, "check for overflow");
+    }
+
+    #[test]
+    fn test_wat_nested_block_comment() {
+        init_logger();
+        let src = r#"(module
+  (;
+    TODO: drop the start function (; see spec ;) once
+      the host calls main
+  ;)
+  (func $main (; TODO: inline block ;) nop)
+  (data (i32.const 0) "(; TODO: not a comment ;)"))
+"#;
+        let todos = test_extract_marked_items(Path::new("main.wat"), src, &todo_config());
+        assert_eq!(todos.len(), 2);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(
+            todos[0].message,
+            "drop the start function (; see spec ;) once the host calls main"
+        );
+        assert_eq!(todos[1].line_number, 6);
+        assert_eq!(todos[1].message, "inline block");
+    }
+}
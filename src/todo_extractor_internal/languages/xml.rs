@@ -0,0 +1,65 @@
+// src/languages/xml.rs
+
+use crate::todo_extractor_internal::aggregator::{parse_comments, CommentLine};
+use crate::todo_extractor_internal::languages::common::CommentParser;
+use pest_derive::Parser;
+use std::marker::PhantomData;
+
+#[derive(Parser)]
+#[grammar = "todo_extractor_internal/languages/xml.pest"]
+pub struct XmlParser;
+
+impl CommentParser for XmlParser {
+    fn parse_comments(file_content: &str) -> Vec<CommentLine> {
+        parse_comments::<Self, Rule>(PhantomData, Rule::xml_file, file_content)
+    }
+}
+
+#[cfg(test)]
+mod xml_tests {
+    use crate::todo_extractor_internal::aggregator::MarkerConfig;
+    use std::path::Path;
+
+    use crate::test_utils::{init_logger, test_extract_marked_items};
+
+    #[test]
+    fn test_xml_comment() {
+        init_logger();
+        let src = r#"<?xml version="1.0" encoding="UTF-8"?>
+<config>
+  <!-- TODO: move the timeout into the environment -->
+  <timeout>30</timeout>
+</config>
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("config.xml"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 3);
+        assert_eq!(todos[0].message, "move the timeout into the environment");
+    }
+
+    #[test]
+    fn test_xml_ignore_comment_inside_cdata() {
+        init_logger();
+        let src = r#"<xsl:stylesheet version="1.0" xmlns:xsl="http://www.w3.org/1999/XSL/Transform">
+  <xsl:template match="/">
+    <script><![CDATA[
+      var s = "<!-- TODO: not a comment -->";
+    ]]></script>
+    <!-- TODO: real comment -->
+  </xsl:template>
+</xsl:stylesheet>
+"#;
+        let config = MarkerConfig {
+            markers: vec!["TODO:".to_string()],
+            ..Default::default()
+        };
+        let todos = test_extract_marked_items(Path::new("page.xsl"), src, &config);
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].line_number, 6);
+        assert_eq!(todos[0].message, "real comment");
+    }
+}
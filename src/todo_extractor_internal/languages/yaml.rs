@@ -28,6 +28,7 @@ mod yaml_tests {
 key: value"#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("config.yaml"), src, &config);
         assert_eq!(todos.len(), 1);
@@ -48,6 +49,7 @@ key: value"#;
       - KEY=value"#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("config.yaml"), src, &config);
 
@@ -70,6 +72,7 @@ services:
     image: apache"#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string(), "FIXME:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("docker-compose.yaml"), src, &config);
 
@@ -89,6 +92,7 @@ services:
   message3: "Normal value""#;
         let config = MarkerConfig {
             markers: vec!["TODO:".to_string()],
+            ..Default::default()
         };
         let todos = test_extract_marked_items(Path::new("config.yaml"), src, &config);
 
@@ -1,11 +1,12 @@
+use crate::todo_extractor_internal::aggregator::split_stable_id;
 use crate::todo_md_internal::TodoCollection;
 use crate::MarkedItem;
 use log::{debug, info, warn};
 use regex::Regex;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::fs;
-use std::io;
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -35,35 +36,7 @@ impl From<io::Error> for TodoError {
 pub fn validate_todo_file(todo_path: &std::path::Path) -> bool {
     // TODO: add tests for this function
     match fs::read_to_string(todo_path) {
-        Ok(content) => {
-            if content.is_empty() {
-                info!("Empty TODO.md file");
-                return true;
-            }
-            // Expected patterns for a marker header, section header, and a TODO item line.
-            let marker_re = Regex::new(r"^#\s+\w+").unwrap();
-            let section_re = Regex::new(r"^##\s+(.*)$").unwrap();
-            let todo_re = Regex::new(r"^\*\s+\[(.+):(\d+)\]\(.+#L\d+\):\s*(.+)$").unwrap();
-            // Check each non‑empty line for a valid pattern.
-            for (i, line) in content.lines().enumerate() {
-                let line = line.trim();
-                if line.is_empty() {
-                    continue;
-                }
-                if !(marker_re.is_match(line)
-                    || section_re.is_match(line)
-                    || todo_re.is_match(line))
-                {
-                    warn!(
-                        "Invalid format on line {line_num}: {line}",
-                        line_num = i + 1,
-                        line = line
-                    );
-                    return false;
-                }
-            }
-            true
-        }
+        Ok(content) => validate_todo_content(&content),
         Err(e) => {
             warn!(
                 "Failed to read {path}: {e}",
@@ -75,6 +48,103 @@ pub fn validate_todo_file(todo_path: &std::path::Path) -> bool {
     }
 }
 
+/// An entry's location: `[<file>:<line>](<link>)`, or `<file>:<line>` as
+/// written under `--no-links`. Groups 1-2 or 3-4 hold the file and line.
+const ENTRY_LOCATION: &str = r"(?:\[(.+):(\d+)\]\(.+?\)|(\S+):(\d+))";
+
+/// The optional `` in `<scope>` `` after an entry's location (group 5 when
+/// following [`ENTRY_LOCATION`]).
+const ENTRY_SCOPE: &str = r"(?: in `([^`]+)`)?";
+
+/// A `--flat` line: `<file>:<line>: [<MARKER>] <message>`.
+const FLAT_ENTRY: &str = r"^(\S+):(\d+): \[(\w+)\] (.+)$";
+
+/// File and line of an entry matched with [`ENTRY_LOCATION`].
+fn entry_location(caps: &regex::Captures) -> (String, usize) {
+    let (file, line) = match caps.get(1) {
+        Some(file) => (file, &caps[2]),
+        None => (caps.get(3).expect("bare location"), &caps[4]),
+    };
+    (file.as_str().to_string(), line.parse().unwrap_or(0))
+}
+
+/// First and last line of a `--preamble` block.
+const PREAMBLE_START: &str = "<!-- preamble -->";
+const PREAMBLE_END: &str = "<!-- end preamble -->";
+
+/// The lines of TODO.md text with their 0-based index, leaving out the
+/// `--preamble` block, whose free text matches none of the entry formats.
+fn content_lines(content: &str) -> Vec<(usize, &str)> {
+    let mut in_preamble = false;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| match line.trim() {
+            PREAMBLE_START => {
+                in_preamble = true;
+                false
+            }
+            PREAMBLE_END => {
+                in_preamble = false;
+                false
+            }
+            _ => !in_preamble,
+        })
+        .collect()
+}
+
+/// Content-level half of [`validate_todo_file`], for callers that already
+/// hold the TODO.md text.
+fn validate_todo_content(content: &str) -> bool {
+    if content.is_empty() {
+        info!("Empty TODO.md file");
+        return true;
+    }
+    // Expected patterns for a marker header, section header, and a TODO item line.
+    let marker_re = Regex::new(r"^#\s+\w+").unwrap();
+    let section_re = Regex::new(r"^##\s+(.*)$").unwrap();
+    let todo_re = Regex::new(&format!(r"^\*\s+{ENTRY_LOCATION}{ENTRY_SCOPE}:\s*(.+)$")).unwrap();
+    // `--dedup-global` groups: a bare message bullet followed by location-only bullets.
+    let shared_re = Regex::new(r"^\*\s+(.+)$").unwrap();
+    let occurrence_re = Regex::new(&format!(r"^\*\s+{ENTRY_LOCATION}{ENTRY_SCOPE}$")).unwrap();
+    // `--summary-header` line, e.g. `<!-- 15 TODO, 3 FIXME -->`.
+    let html_comment_re = Regex::new(r"^<!--.*-->$").unwrap();
+    // `--group-by assignee` nests marker headers under assignee sections.
+    let nested_marker_re = Regex::new(r"^###\s+\w+").unwrap();
+    let flat_re = Regex::new(FLAT_ENTRY).unwrap();
+    let lines: Vec<(usize, &str)> = content_lines(content)
+        .into_iter()
+        .map(|(i, line)| (i, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .collect();
+    // Check each non‑empty line for a valid pattern.
+    for (pos, &(i, line)) in lines.iter().enumerate() {
+        // Any other bullet is only a shared message when a location follows,
+        // so a mangled entry still fails instead of passing as one.
+        let is_shared = shared_re.is_match(line)
+            && lines
+                .get(pos + 1)
+                .is_some_and(|(_, next)| occurrence_re.is_match(next));
+        if !(marker_re.is_match(line)
+            || section_re.is_match(line)
+            || todo_re.is_match(line)
+            || occurrence_re.is_match(line)
+            || is_shared
+            || html_comment_re.is_match(line)
+            || nested_marker_re.is_match(line)
+            || flat_re.is_match(line))
+        {
+            warn!(
+                "Invalid format on line {line_num}: {line}",
+                line_num = i + 1,
+                line = line
+            );
+            return false;
+        }
+    }
+    true
+}
+
 /// Reads the existing TODO.md file (in the new sectioned format) and returns a vector of `MarkedItem`s.
 ///
 /// The new format groups TODO items under section headers of the form:
@@ -85,49 +155,147 @@ pub fn validate_todo_file(todo_path: &std::path::Path) -> bool {
 /// ```
 ///
 /// This function uses regex to detect section headers to set the current file context, and then
-/// parses subsequent todo item lines accordingly.
+/// parses subsequent todo item lines accordingly. Link-less `* <file-path>:<line_number>: <message>`
+/// entries (`--no-links`) are read too. A `` in `<scope>` `` between the link and the
+/// colon (see [`MarkerConfig::scope`](crate::MarkerConfig::scope)) is read into the item's
+/// scope. The line number is read from the link label,
+/// so any `--line-anchor` style (e.g. `#lines-<line_number>`) parses back.
+/// Header-less `<file-path>:<line_number>: [<MARKER>] <message>` lines
+/// (`--flat`) are read as well.
 pub fn read_todo_file(todo_path: &Path) -> Result<Vec<MarkedItem>, TodoError> {
+    // A missing TODO.md (e.g. under `--no-create`) is an empty one.
+    if !todo_path.exists() {
+        return Ok(Vec::new());
+    }
     if !validate_todo_file(todo_path) {
         return Err(TodoError::Parse("TODO.md validation failed".to_string()));
     }
 
     let content = fs::read_to_string(todo_path)?;
+    parse_todo_content(&content)
+}
+
+/// Validate and parse TODO.md text into `MarkedItem`s. See [`read_todo_file`].
+fn parse_todo_content(content: &str) -> Result<Vec<MarkedItem>, TodoError> {
+    Ok(parse_todo_entries(content)?
+        .into_iter()
+        .map(|(_, item)| item)
+        .collect())
+}
+
+/// [`parse_todo_content`], with the 0-based index of the line each item
+/// was read from.
+fn parse_todo_entries(content: &str) -> Result<Vec<(usize, MarkedItem)>, TodoError> {
+    if !validate_todo_content(content) {
+        return Err(TodoError::Parse("TODO.md validation failed".to_string()));
+    }
 
     let mut todos = Vec::new();
     let marker_re = Regex::new(r"^#\s+(\w+)").unwrap();
     let section_re = Regex::new(r"^##\s+(.*)$").unwrap();
-    let todo_re = Regex::new(r"^\*\s+\[(.+):(\d+)\]\(.+#L\d+\):\s*(.+)$").unwrap();
+    let todo_re = Regex::new(&format!(r"^\*\s+{ENTRY_LOCATION}{ENTRY_SCOPE}:\s*(.+)$")).unwrap();
+    let occurrence_re = Regex::new(&format!(r"^\*\s+{ENTRY_LOCATION}{ENTRY_SCOPE}$")).unwrap();
+    let shared_re = Regex::new(r"^\*\s+(.+)$").unwrap();
+    let assignee_re = Regex::new(&format!(r"^##\s+(@\S+|{})$", regex::escape(UNASSIGNED))).unwrap();
+    let nested_marker_re = Regex::new(r"^###\s+(\w+)").unwrap();
+    let flat_re = Regex::new(FLAT_ENTRY).unwrap();
     let mut current_file: Option<String> = None;
     let mut current_marker: Option<String> = None;
-    for line in content.lines() {
+    let mut current_shared: Option<String> = None;
+    for (index, line) in content_lines(content) {
         let line = line.trim();
-        if line.is_empty() {
+        // Skip blank lines and the generated `--summary-header` comment.
+        if line.is_empty() || (line.starts_with("<!--") && line.ends_with("-->")) {
+            continue;
+        }
+        // A `--flat` line carries everything itself.
+        if let Some(caps) = flat_re.captures(line) {
+            todos.push((
+                index,
+                MarkedItem {
+                    file_path: PathBuf::from(&caps[1]),
+                    line_number: caps[2].parse().unwrap_or(0),
+                    message: caps[4].to_string(),
+                    marker: caps[3].to_string(),
+                    scope: None,
+                },
+            ));
             continue;
         }
         // If the line is a marker header, update the current marker
         if let Some(caps) = marker_re.captures(line) {
+            current_marker = Some(caps[1].to_string());
+            current_shared = None;
+            continue;
+        }
+        // `--group-by assignee` sections: the file comes from each bullet's
+        // label and the marker from the nested `### <marker>` header. A
+        // regular `## @scope/file` header parses the same way, since the
+        // label carries the file path too.
+        if assignee_re.is_match(line) {
+            current_file = None;
+            current_shared = None;
+            continue;
+        }
+        if let Some(caps) = nested_marker_re.captures(line) {
             current_marker = Some(caps[1].to_string());
             continue;
         }
+        // A `--keep-reviewed` placeholder holds no items.
+        if line.ends_with(REVIEWED_SUFFIX) {
+            current_file = None;
+            current_shared = None;
+            continue;
+        }
         // If the line is a section header, update the current file context.
         if let Some(caps) = section_re.captures(line) {
             current_file = Some(caps[1].trim().to_string());
+            current_shared = None;
             continue;
         }
         // If the line matches a TODO item, parse it.
         if let Some(caps) = todo_re.captures(line) {
-            let file_path_str = current_file.clone().unwrap_or_else(|| caps[1].to_string());
-            let file_path = PathBuf::from(file_path_str);
-            let line_number = caps[2].parse::<usize>().unwrap_or(0);
-            let message = caps[3].to_string();
+            let (label_file, line_number) = entry_location(&caps);
+            let file_path = PathBuf::from(current_file.clone().unwrap_or(label_file));
+            let message = caps[6].to_string();
             let marker = current_marker.clone().unwrap_or_else(|| "TODO".to_string());
-            todos.push(MarkedItem {
-                file_path,
-                line_number,
-                message,
-                marker,
-            });
+            todos.push((
+                index,
+                MarkedItem {
+                    file_path,
+                    line_number,
+                    message,
+                    marker,
+                    scope: caps.get(5).map(|m| m.as_str().to_string()),
+                },
+            ));
+            continue;
+        }
+        // A location-only sub-bullet is one occurrence of the current shared message.
+        if let Some(caps) = occurrence_re.captures(line) {
+            if let Some(message) = &current_shared {
+                let (file, line_number) = entry_location(&caps);
+                todos.push((
+                    index,
+                    MarkedItem {
+                        file_path: PathBuf::from(file),
+                        line_number,
+                        message: message.clone(),
+                        marker: current_marker.clone().unwrap_or_else(|| "TODO".to_string()),
+                        scope: caps.get(5).map(|m| m.as_str().to_string()),
+                    },
+                ));
+            }
+            continue;
         }
+        // Any other bullet opens a shared message group.
+        if let Some(caps) = shared_re.captures(line) {
+            current_shared = Some(caps[1].to_string());
+        }
+    }
+    // `--stable-ids` prefixes are derived from the item, not part of it.
+    for (_, item) in &mut todos {
+        item.drop_derived_id();
     }
     Ok(todos)
 }
@@ -136,12 +304,51 @@ pub fn sync_todo_file(
     todo_path: &Path,
     new_todos: Vec<MarkedItem>,
     scanned_files: Vec<PathBuf>,
+) -> Result<(), TodoError> {
+    sync_todo_file_with_options(
+        todo_path,
+        new_todos,
+        scanned_files,
+        &WriteOptions::default(),
+    )
+}
+
+/// Same as [`sync_todo_file`], but renders the merged result with the given
+/// [`WriteOptions`].
+///
+/// The whole read-merge-write runs under an exclusive advisory lock on
+/// TODO.md, so concurrent runs (e.g. CI matrix jobs scanning disjoint file
+/// sets) serialize instead of overwriting each other's sections. All I/O
+/// goes through the locked handle because Windows locks are mandatory.
+pub fn sync_todo_file_with_options(
+    todo_path: &Path,
+    new_todos: Vec<MarkedItem>,
+    scanned_files: Vec<PathBuf>,
+    options: &WriteOptions,
 ) -> Result<(), TodoError> {
     // TODO maybe simplify the logic of this function
 
+    let mut file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(todo_path)?;
+    file.lock()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+
     let mut existing_collection = TodoCollection::new();
+    let mut existing_order = Vec::new();
+    // `--keep-reviewed`: (marker, file) sections that already were
+    // placeholders, plus those whose last entry this scan removes.
+    let mut reviewed = if options.keep_reviewed {
+        reviewed_sections(&content)
+    } else {
+        BTreeSet::new()
+    };
 
-    match read_todo_file(todo_path) {
+    match parse_todo_content(&content) {
         Ok(existing_todos) => {
             let filtered_todos: Vec<MarkedItem> = existing_todos
                 .into_iter()
@@ -152,6 +359,7 @@ pub fn sync_todo_file(
 
             // Create a TodoCollection from the filtered existing TODO items.
             for item in filtered_todos {
+                existing_order.push(item.file_path.clone());
                 existing_collection.add_item(item);
             }
         }
@@ -164,21 +372,418 @@ pub fn sync_todo_file(
 
     // Create a TodoCollection from the new TODO items.
     let mut new_collection = TodoCollection::new();
+    let mut new_todos_order = Vec::new();
     for item in new_todos {
+        new_todos_order.push(item.file_path.clone());
         new_collection.add_item(item);
     }
 
+    if options.keep_reviewed {
+        for file in &scanned_files {
+            for item in existing_collection.todos.get(file).into_iter().flatten() {
+                reviewed.insert((item.marker.clone(), file.clone()));
+            }
+        }
+    }
+
+    // `--no-sort`: files already in TODO.md keep their place, new ones
+    // follow in scan order.
+    let file_order: Vec<PathBuf> = if options.no_sort {
+        existing_order.into_iter().chain(new_todos_order).collect()
+    } else {
+        Vec::new()
+    };
+
     // Merge new TODO items into the existing collection, updating only scanned files.
     existing_collection.merge(new_collection, scanned_files);
 
     // Convert the merged collection back into a sorted vector of MarkedItems.
-    let merged_todos = existing_collection.to_sorted_vec();
+    let mut merged_todos = if options.no_sort {
+        existing_collection.to_ordered_vec(&file_order)
+    } else {
+        existing_collection.to_sorted_vec()
+    };
+    merged_todos.retain(|item| {
+        !options.closed.iter().any(|(marker, file, line)| {
+            item.marker.eq_ignore_ascii_case(marker) && item.is_at(file, *line)
+        })
+    });
+
+    // A placeholder only stays while its file exists and has no entries.
+    reviewed.retain(|(_, file)| file.exists() && !existing_collection.todos.contains_key(file));
 
     // Write the merged and sorted TODO items back to the TODO.md file in the new sectioned format.
-    write_todo_file(todo_path, merged_todos)?;
+    let rendered = render_sections(merged_todos, &reviewed, options);
+    if without_timestamp(&rendered) == without_timestamp(&content) {
+        // Nothing but the `--timestamp` line would change: keep the file
+        // (and its old timestamp) so repeated runs are no-ops.
+        return Ok(());
+    }
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(rendered.as_bytes())?;
+    // The lock is released when `file` is dropped.
     Ok(())
 }
 
+/// `--rewrite-links`: fix the line numbers of TODO.md entries whose
+/// comment moved, editing only those lines so the file's layout and order
+/// stay as they are. `current` holds freshly extracted items of the files
+/// TODO.md references. Each entry is matched to a current item of the same
+/// file and marker, preferring an identical message over one that only
+/// differs in case, whitespace or punctuation, and the nearest line among
+/// equals. Returns the number of entries that moved.
+pub fn rewrite_line_numbers(todo_path: &Path, current: &[MarkedItem]) -> Result<usize, TodoError> {
+    let content = fs::read_to_string(todo_path)?;
+    let entries = parse_todo_entries(&content)?;
+
+    let mut used = vec![false; current.len()];
+    let mut found: Vec<Option<usize>> = vec![None; entries.len()];
+    let same_message = |fuzzy: bool, a: &str, b: &str| {
+        if fuzzy {
+            normalized_message(a) == normalized_message(b)
+        } else {
+            a == b
+        }
+    };
+    // Exact matches first, so a fuzzy one can't take an item another entry
+    // matches exactly.
+    for fuzzy in [false, true] {
+        for (slot, (_, entry)) in found.iter_mut().zip(&entries) {
+            if slot.is_some() {
+                continue;
+            }
+            *slot = current
+                .iter()
+                .enumerate()
+                .filter(|(i, item)| {
+                    !used[*i]
+                        && item.file_path == entry.file_path
+                        && item.marker == entry.marker
+                        && same_message(fuzzy, &item.message, &entry.message)
+                })
+                .min_by_key(|(_, item)| item.line_number.abs_diff(entry.line_number))
+                .map(|(i, _)| i);
+            if let Some(i) = *slot {
+                used[i] = true;
+            }
+        }
+    }
+
+    let mut lines: Vec<String> = content.split_inclusive('\n').map(str::to_string).collect();
+    let mut moved = 0;
+    for ((index, entry), slot) in entries.iter().zip(found) {
+        let Some(new_line) = slot.map(|i| current[i].line_number) else {
+            continue;
+        };
+        if new_line != entry.line_number {
+            lines[*index] = relocate_entry(
+                &lines[*index],
+                &entry.file_path.display().to_string(),
+                entry.line_number,
+                new_line,
+            );
+            moved += 1;
+        }
+    }
+    if moved > 0 {
+        fs::write(todo_path, lines.concat())?;
+    }
+    Ok(moved)
+}
+
+/// Entries `after` has that `before` doesn't, and the other way round, as
+/// `(added, removed)`. Entries are compared by file, marker and message, so
+/// one that only moved to another line is not a change.
+pub fn diff_entries(
+    before: &[MarkedItem],
+    after: &[MarkedItem],
+) -> (Vec<MarkedItem>, Vec<MarkedItem>) {
+    let key = |item: &MarkedItem| {
+        (
+            item.file_path.clone(),
+            item.marker.clone(),
+            item.message.clone(),
+        )
+    };
+    let mut unmatched: Vec<Option<&MarkedItem>> = before.iter().map(Some).collect();
+    let mut added = Vec::new();
+    for item in after {
+        let found = unmatched
+            .iter_mut()
+            .find(|old| old.is_some_and(|old| key(old) == key(item)));
+        match found {
+            Some(old) => *old = None,
+            None => added.push(item.clone()),
+        }
+    }
+    let removed = unmatched.into_iter().flatten().cloned().collect();
+    (added, removed)
+}
+
+/// Which items count as duplicates of an earlier one (`--dedupe-by`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupeBy {
+    /// Keep every item.
+    #[default]
+    None,
+    /// Same message, wherever it is.
+    Message,
+    /// Same file and line.
+    Line,
+    /// Same file, line, marker and message.
+    Full,
+}
+
+/// Drop every item that duplicates an earlier one according to `by`,
+/// keeping the first.
+pub fn dedupe_items(items: Vec<MarkedItem>, by: DedupeBy) -> Vec<MarkedItem> {
+    let mut seen = BTreeSet::new();
+    items
+        .into_iter()
+        .filter(|item| {
+            let key = match by {
+                DedupeBy::None => return true,
+                DedupeBy::Message => (None, None, None, Some(item.message.clone())),
+                DedupeBy::Line => (
+                    Some(item.file_path.clone()),
+                    Some(item.line_number),
+                    None,
+                    None,
+                ),
+                DedupeBy::Full => (
+                    Some(item.file_path.clone()),
+                    Some(item.line_number),
+                    Some(item.marker.clone()),
+                    Some(item.message.clone()),
+                ),
+            };
+            seen.insert(key)
+        })
+        .collect()
+}
+
+/// A message reduced to its lowercase words, for matching edited messages.
+fn normalized_message(message: &str) -> String {
+    message
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `line` with the entry's `<file>:<old>` label, and the line number in
+/// its link anchor (the part after the last `#`), changed to `new`.
+fn relocate_entry(line: &str, file: &str, old: usize, new: usize) -> String {
+    let label = format!("{file}:{old}");
+    let Some(start) = line.find(&label) else {
+        return line.to_string();
+    };
+    let end = start + label.len();
+    let mut out = format!("{}{file}:{new}", &line[..start]);
+    let rest = &line[end..];
+    let Some((target, tail)) = rest
+        .strip_prefix("](")
+        .and_then(|target| target.split_once(')'))
+    else {
+        out.push_str(rest);
+        return out;
+    };
+    let (path, anchor) = target.rsplit_once('#').unwrap_or((target, ""));
+    let old = old.to_string();
+    let anchor = Regex::new(r"\d+")
+        .unwrap()
+        .replace_all(anchor, |caps: &regex::Captures| {
+            if caps[0] == old {
+                new.to_string()
+            } else {
+                caps[0].to_string()
+            }
+        });
+    if target.contains('#') {
+        out.push_str(&format!("]({path}#{anchor}){tail}"));
+    } else {
+        out.push_str(&format!("]({target}){tail}"));
+    }
+    out
+}
+
+/// Rendering options for [`write_todo_file_with_options`].
+///
+/// `Default` reproduces the stable output format described in the README.
+#[derive(Debug, Clone, Default)]
+pub struct WriteOptions {
+    /// When set, link targets are made relative to this directory instead of
+    /// being written as given (i.e. relative to the repository root). The
+    /// link label and the `## <file>` header keep the repo-relative path so
+    /// TODO.md still round-trips through [`read_todo_file`].
+    pub link_base: Option<PathBuf>,
+    /// Collapse items whose marker and message are identical across several
+    /// files into one entry, listing every location as a sub-bullet:
+    ///
+    /// ```markdown
+    /// # TODO
+    /// * <message>
+    ///   * [<file-path>:<line_number>](<file-path>#L<line_number>)
+    /// ```
+    ///
+    /// Shared entries come before the per-file sections of their marker.
+    pub dedup_global: bool,
+    /// Start the file with an HTML comment counting the items per marker,
+    /// e.g. `<!-- 15 TODO, 3 FIXME -->`. It doesn't render, and
+    /// [`read_todo_file`] skips it.
+    pub summary_header: bool,
+    /// Group items by assignee (see [`MarkedItem::assignee`]) instead of by
+    /// marker, with the markers nested inside:
+    ///
+    /// ```markdown
+    /// ## @<assignee>
+    /// ### <marker>
+    /// * [<file-path>:<line_number>](<file-path>#L<line_number>): <message>
+    /// ```
+    ///
+    /// Items without an assignee go under `## (unassigned)`, last.
+    pub group_by_assignee: bool,
+    /// Start the file with `<!-- generated: <RFC3339 time> -->`. The line is
+    /// ignored when reading TODO.md and when deciding whether it changed.
+    pub timestamp: bool,
+    /// When a sync removes the last entry of a scanned file, keep its
+    /// section as a `## <file> — (none)` placeholder showing the file was
+    /// reviewed. Only [`sync_todo_file_with_options`] knows which files had
+    /// entries, so full rewrites don't emit placeholders.
+    pub keep_reviewed: bool,
+    /// Anchor appended to each link target, with `{line}` replaced by the
+    /// line number. `None` means GitHub/GitLab style `#L{line}`; Bitbucket
+    /// wants `#lines-{line}`.
+    pub line_anchor: Option<String>,
+    /// Write entries as plain `* <file-path>:<line_number>: <message>`,
+    /// without link markup, for renderers that mangle it.
+    pub no_links: bool,
+    /// Order the file sections of each marker by descending number of
+    /// items (ties by path) instead of by path alone.
+    pub sort_files_by_count: bool,
+    /// Write one `<file>:<line>: [<MARKER>] <message>` line per item, in
+    /// file and line order, with no headers, for piping into other tools.
+    /// Every other layout option is ignored, and scopes aren't written.
+    pub flat: bool,
+    /// `(marker, file, line)` items closed by a commit message
+    /// (`--close-from-commits`). [`sync_todo_file_with_options`] drops the
+    /// entries there with that marker (compared case-insensitively),
+    /// scanned or not (see [`MarkedItem::is_at`]).
+    pub closed: Vec<(String, PathBuf, usize)>,
+    /// Roots of independent projects (`--project-roots`). Links to a file
+    /// under one of them are made relative to that root (the deepest one
+    /// when they nest), taking precedence over [`WriteOptions::link_base`].
+    /// Like `link_base`, the label keeps the repo-relative path. Roots are
+    /// compared component-wise, so they must be given in the same form
+    /// (relative or absolute) as the file paths.
+    pub project_roots: Vec<PathBuf>,
+    /// Keep discovery order instead of sorting: markers, file sections and
+    /// entries appear in the order the items were first seen (the order of
+    /// the scanned files, then line order within each file). Items already
+    /// in TODO.md keep their place when it is synced. The output then
+    /// depends on how files are passed, so diffs are less stable.
+    pub no_sort: bool,
+    /// Fixed text written at the top of the file (after the `--timestamp`
+    /// line), e.g. "Auto-generated — do not edit manually", followed by a
+    /// blank line. It is wrapped in `<!-- preamble -->` and
+    /// `<!-- end preamble -->` lines, which don't render, so
+    /// [`read_todo_file`] can skip it whatever it says.
+    pub preamble: Option<String>,
+    /// Report every marker in uppercase, so `todo`, `Todo` and `TODO`
+    /// items share one `# TODO` section instead of three.
+    pub uppercase_markers: bool,
+    /// Start every message with a short ID, e.g. `[T:a1b2c3] Parse flags`,
+    /// hashed from the marker and the message. It stays the same when the
+    /// item moves to another line, so reviewers can refer to it; reading
+    /// TODO.md drops it again.
+    pub stable_ids: bool,
+}
+
+/// End of a `--keep-reviewed` placeholder header.
+const REVIEWED_SUFFIX: &str = " — (none)";
+
+/// `(marker, file)` of every `--keep-reviewed` placeholder in `content`.
+fn reviewed_sections(content: &str) -> BTreeSet<(String, PathBuf)> {
+    let marker_re = Regex::new(r"^#\s+(\w+)").unwrap();
+    let mut current_marker = None;
+    let mut reviewed = BTreeSet::new();
+    for line in content.lines().map(str::trim) {
+        if let Some(caps) = marker_re.captures(line) {
+            current_marker = Some(caps[1].to_string());
+        } else if let (Some(marker), Some(file)) = (
+            &current_marker,
+            line.strip_prefix("## ")
+                .and_then(|l| l.strip_suffix(REVIEWED_SUFFIX)),
+        ) {
+            reviewed.insert((marker.clone(), PathBuf::from(file.trim())));
+        }
+    }
+    reviewed
+}
+
+/// Start of the `--timestamp` line.
+const GENERATED_PREFIX: &str = "<!-- generated: ";
+
+/// `content` without its leading `--timestamp` line, for comparing renders
+/// that differ only in when they were generated.
+pub fn without_timestamp(content: &str) -> &str {
+    match content.split_once('\n') {
+        Some((first, rest)) if first.starts_with(GENERATED_PREFIX) => rest,
+        _ => content,
+    }
+}
+
+/// Section header for items without an `@assignee` under `--group-by assignee`.
+const UNASSIGNED: &str = "(unassigned)";
+
+/// Default `--line-anchor`.
+const DEFAULT_LINE_ANCHOR: &str = "#L{line}";
+
+impl WriteOptions {
+    /// An entry's location: `[<file>:<line>](<link>)`, or plain
+    /// `<file>:<line>` under [`WriteOptions::no_links`].
+    fn entry_location(&self, item: &MarkedItem) -> String {
+        let label = format!("{}:{}", item.file_path.display(), item.line_number);
+        if self.no_links {
+            label
+        } else {
+            format!(
+                "[{label}]({})",
+                self.link_target(&item.file_path, item.line_number)
+            )
+        }
+    }
+
+    /// The `(...)` part of an entry's link: the path plus the line anchor.
+    fn link_target(&self, file: &Path, line: usize) -> String {
+        let project_root = self
+            .project_roots
+            .iter()
+            .filter(|root| file.starts_with(root))
+            .max_by_key(|root| root.components().count());
+        let path = match project_root.or(self.link_base.as_ref()) {
+            Some(base) => relative_path(file, base).display().to_string(),
+            None => file.display().to_string(),
+        };
+        let anchor = self.line_anchor.as_deref().unwrap_or(DEFAULT_LINE_ANCHOR);
+        format!("{path}{}", anchor.replace("{line}", &line.to_string()))
+    }
+}
+
+/// Compute `file` relative to `base`. Mixed absolute/relative inputs are
+/// resolved against the current directory first; if no relative path
+/// exists (e.g. different drive prefixes on Windows) `file` is returned
+/// unchanged.
+fn relative_path(file: &Path, base: &Path) -> PathBuf {
+    if file.is_absolute() == base.is_absolute() {
+        return pathdiff::diff_paths(file, base).unwrap_or_else(|| file.to_path_buf());
+    }
+    let cwd = std::env::current_dir().unwrap_or_default();
+    pathdiff::diff_paths(cwd.join(file), cwd.join(base)).unwrap_or_else(|| file.to_path_buf())
+}
+
 /// Writes the given list of `TodoItem`s to the TODO.md file in markdown format.
 ///
 /// The output format is grouped by marker (e.g., TODO, FIXME) as top-level headers,
@@ -193,33 +798,171 @@ pub fn sync_todo_file(
 /// - [src/file2.rs:120](src/file2.rs#L120): Correct boundary condition
 ///
 pub fn write_todo_file(todo_path: &Path, todos: Vec<MarkedItem>) -> std::io::Result<()> {
-    // Group by marker, then by file using BTreeMap for sorted output
-    let mut marker_map: BTreeMap<String, BTreeMap<PathBuf, Vec<MarkedItem>>> = BTreeMap::new();
+    write_todo_file_with_options(todo_path, todos, &WriteOptions::default())
+}
+
+/// Same as [`write_todo_file`], but renders entries according to `options`.
+pub fn write_todo_file_with_options(
+    todo_path: &Path,
+    todos: Vec<MarkedItem>,
+    options: &WriteOptions,
+) -> std::io::Result<()> {
+    let rendered = render_todo_file(todos, options);
+    if let Ok(existing) = fs::read_to_string(todo_path) {
+        if without_timestamp(&existing) == without_timestamp(&rendered) {
+            return Ok(());
+        }
+    }
+    fs::write(todo_path, rendered)
+}
+
+/// Render `todos` into TODO.md text without touching the filesystem. See
+/// [`write_todo_file`] for the layout.
+pub fn render_todo_file(todos: Vec<MarkedItem>, options: &WriteOptions) -> String {
+    render_sections(todos, &BTreeSet::new(), options)
+}
+
+/// [`render_todo_file`] plus `--keep-reviewed` placeholders for the given
+/// `(marker, file)` pairs.
+fn render_sections(
+    mut todos: Vec<MarkedItem>,
+    reviewed: &BTreeSet<(String, PathBuf)>,
+    options: &WriteOptions,
+) -> String {
+    if options.uppercase_markers {
+        for item in &mut todos {
+            item.marker = item.marker.to_uppercase();
+        }
+    }
+    if options.stable_ids {
+        // Items carrying an ID from their comment (`--annotate`) keep it.
+        for item in todos
+            .iter_mut()
+            .filter(|item| split_stable_id(&item.message).is_none())
+        {
+            item.message = format!("[T:{}] {}", item.stable_id(), item.message);
+        }
+    }
+    if options.flat {
+        return render_flat(todos, options.no_sort);
+    }
+    let shared_keys = if options.dedup_global {
+        shared_messages(&todos)
+    } else {
+        BTreeSet::new()
+    };
+    // `--no-sort`: position of each marker and file in discovery order.
+    let marker_rank = first_seen(todos.iter().map(|item| item.marker.clone()));
+    let file_rank = first_seen(todos.iter().map(|item| item.file_path.clone()));
+
+    // Group by marker, then by file (or shared message) using BTreeMap for sorted output
+    let mut marker_map: BTreeMap<String, MarkerSection> = BTreeMap::new();
     for item in todos {
+        let section = marker_map.entry(item.marker.clone()).or_default();
+        if shared_keys.contains(&(item.marker.clone(), item.message.clone())) {
+            section
+                .shared
+                .entry(item.message.clone())
+                .or_default()
+                .push(item);
+        } else {
+            section
+                .files
+                .entry(item.file_path.clone())
+                .or_default()
+                .push(item);
+        }
+    }
+    for (marker, file) in reviewed {
         marker_map
-            .entry(item.marker.clone())
+            .entry(marker.clone())
             .or_default()
-            .entry(item.file_path.clone())
-            .or_default()
-            .push(item);
+            .files
+            .entry(file.clone())
+            .or_default();
     }
 
     let mut content = String::new();
+    if options.timestamp {
+        let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        content.push_str(&format!("{GENERATED_PREFIX}{now} -->\n"));
+    }
+    if let Some(preamble) = &options.preamble {
+        content.push_str(&format!(
+            "{PREAMBLE_START}\n{}\n{PREAMBLE_END}\n\n",
+            preamble.trim_end()
+        ));
+    }
+    if options.summary_header && !marker_map.is_empty() {
+        let counts: Vec<String> = marker_map
+            .iter()
+            .filter_map(|(marker, section)| {
+                let count: usize = section.shared.values().map(Vec::len).sum::<usize>()
+                    + section.files.values().map(Vec::len).sum::<usize>();
+                (count > 0).then(|| format!("{count} {marker}"))
+            })
+            .collect();
+        content.push_str(&format!("<!-- {} -->\n", counts.join(", ")));
+    }
+    if options.group_by_assignee {
+        render_by_assignee(&mut content, marker_map, options);
+        return content;
+    }
+    let mut marker_sections: Vec<_> = marker_map.into_iter().collect();
+    if options.no_sort {
+        marker_sections.sort_by_key(|(marker, _)| marker_rank.get(marker).copied());
+    }
     // Write each marker section
-    for (marker, files) in marker_map {
+    for (marker, section) in marker_sections {
         content.push_str(&format!("# {marker}\n"));
+        for (message, items) in &section.shared {
+            content.push_str(&format!("* {message}\n"));
+            let mut sorted_items = items.clone();
+            if !options.no_sort {
+                sorted_items.sort_by(|a, b| {
+                    (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number))
+                });
+            }
+            for item in sorted_items.iter() {
+                content.push_str(&format!(
+                    "  * {location}{scope}\n",
+                    location = options.entry_location(item),
+                    scope = scope_suffix(item),
+                ));
+            }
+        }
+        if !section.shared.is_empty() && !section.files.is_empty() {
+            content.push('\n');
+        }
         // Write each file section under the marker
-        let file_entries: Vec<_> = files.into_iter().collect();
+        let mut file_entries: Vec<_> = section.files.into_iter().collect();
+        if options.no_sort {
+            // Placeholders have no rank (`None`) and so come first.
+            file_entries.sort_by_key(|(file, _)| file_rank.get(file).copied());
+        }
+        if options.sort_files_by_count {
+            // Stable sort over path order, so ties stay lexicographic.
+            file_entries.sort_by_key(|(_, items)| std::cmp::Reverse(items.len()));
+        }
         for (i, (file, items)) in file_entries.iter().enumerate() {
-            content.push_str(&format!("## {file}\n", file = file.display()));
+            if items.is_empty() {
+                content.push_str(&format!(
+                    "## {file}{REVIEWED_SUFFIX}\n",
+                    file = file.display()
+                ));
+            } else {
+                content.push_str(&format!("## {file}\n", file = file.display()));
+            }
             // Sort items by line number for consistency
             let mut sorted_items = items.clone();
-            sorted_items.sort_by_key(|item| item.line_number);
+            if !options.no_sort {
+                sorted_items.sort_by_key(|item| item.line_number);
+            }
             for item in sorted_items.iter() {
                 content.push_str(&format!(
-                    "* [{file}:{line}]({file}#L{line}): {message}\n",
-                    file = item.file_path.display(),
-                    line = item.line_number,
+                    "* {location}{scope}: {message}\n",
+                    location = options.entry_location(item),
+                    scope = scope_suffix(item),
                     message = item.message
                 ));
             }
@@ -229,12 +972,137 @@ pub fn write_todo_file(todo_path: &Path, todos: Vec<MarkedItem>) -> std::io::Res
             }
         }
     }
-    // Write the final content to the TODO.md file
-    fs::write(todo_path, content)
+    content
+}
+
+/// Index of the first occurrence of each key, for `--no-sort`.
+fn first_seen<K: Ord>(keys: impl Iterator<Item = K>) -> BTreeMap<K, usize> {
+    let mut ranks = BTreeMap::new();
+    for key in keys {
+        let next = ranks.len();
+        ranks.entry(key).or_insert(next);
+    }
+    ranks
+}
+
+/// `--flat` layout (see [`WriteOptions::flat`]).
+fn render_flat(todos: Vec<MarkedItem>, no_sort: bool) -> String {
+    let todos = if no_sort {
+        todos
+    } else {
+        let mut collection = TodoCollection::new();
+        for item in todos {
+            collection.add_item(item);
+        }
+        collection.to_sorted_vec()
+    };
+    todos
+        .iter()
+        .map(|item| {
+            format!(
+                "{}:{}: [{}] {}\n",
+                item.file_path.display(),
+                item.line_number,
+                item.marker,
+                item.message
+            )
+        })
+        .collect()
+}
+
+/// `--group-by assignee` layout (see [`WriteOptions::group_by_assignee`]).
+/// Shared `--dedup-global` groups are not kept: every item is listed on
+/// its own.
+fn render_by_assignee(
+    content: &mut String,
+    marker_map: BTreeMap<String, MarkerSection>,
+    options: &WriteOptions,
+) {
+    // assignee -> marker -> items; `None` (unassigned) sorts first, so it
+    // is moved to the end below.
+    let mut by_assignee: BTreeMap<Option<String>, BTreeMap<String, Vec<MarkedItem>>> =
+        BTreeMap::new();
+    for (marker, section) in marker_map {
+        for item in section
+            .shared
+            .into_values()
+            .chain(section.files.into_values())
+            .flatten()
+        {
+            by_assignee
+                .entry(item.assignee().map(str::to_string))
+                .or_default()
+                .entry(marker.clone())
+                .or_default()
+                .push(item);
+        }
+    }
+    let unassigned = by_assignee.remove(&None);
+    let sections: Vec<_> = by_assignee
+        .into_iter()
+        .map(|(assignee, markers)| (format!("@{}", assignee.unwrap_or_default()), markers))
+        .chain(unassigned.map(|markers| (UNASSIGNED.to_string(), markers)))
+        .collect();
+
+    for (i, (header, markers)) in sections.iter().enumerate() {
+        if i > 0 {
+            content.push('\n');
+        }
+        content.push_str(&format!("## {header}\n"));
+        for (marker, items) in markers {
+            content.push_str(&format!("### {marker}\n"));
+            let mut sorted_items = items.clone();
+            if !options.no_sort {
+                sorted_items.sort_by(|a, b| {
+                    (&a.file_path, a.line_number).cmp(&(&b.file_path, b.line_number))
+                });
+            }
+            for item in sorted_items.iter() {
+                content.push_str(&format!(
+                    "* {location}{scope}: {message}\n",
+                    location = options.entry_location(item),
+                    scope = scope_suffix(item),
+                    message = item.message
+                ));
+            }
+        }
+    }
+}
+
+/// Text between an entry's link and its colon: `` in `<scope>` `` when the
+/// item has a scope, otherwise nothing.
+fn scope_suffix(item: &MarkedItem) -> String {
+    item.scope
+        .as_ref()
+        .map(|scope| format!(" in `{scope}`"))
+        .unwrap_or_default()
+}
+
+/// Items rendered under one `# <marker>` header.
+#[derive(Default)]
+struct MarkerSection {
+    shared: BTreeMap<String, Vec<MarkedItem>>,
+    files: BTreeMap<PathBuf, Vec<MarkedItem>>,
+}
+
+/// `(marker, message)` pairs that occur in more than one file.
+fn shared_messages(todos: &[MarkedItem]) -> BTreeSet<(String, String)> {
+    let mut files_by_key: BTreeMap<(String, String), BTreeSet<&Path>> = BTreeMap::new();
+    for item in todos {
+        files_by_key
+            .entry((item.marker.clone(), item.message.clone()))
+            .or_default()
+            .insert(&item.file_path);
+    }
+    files_by_key
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .map(|(key, _)| key)
+        .collect()
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     use crate::test_utils::init_logger;
     use crate::MarkedItem;
@@ -242,6 +1110,16 @@ mod tests {
     use std::path::PathBuf;
     use tempfile::tempdir;
 
+    pub(crate) fn item(file: &str, line: usize, marker: &str, message: &str) -> MarkedItem {
+        MarkedItem {
+            file_path: PathBuf::from(file),
+            line_number: line,
+            message: message.to_string(),
+            marker: marker.to_string(),
+            scope: None,
+        }
+    }
+
     #[test]
     fn test_sync_todo_file() {
         init_logger();
@@ -257,12 +1135,14 @@ mod tests {
                 line_number: 10,
                 message: "Refactor this function".to_string(),
                 marker: "TODO".to_string(),
+                scope: None,
             },
             MarkedItem {
                 file_path: PathBuf::from("src/lib.rs"),
                 line_number: 5,
                 message: "Add error handling".to_string(),
                 marker: "TODO".to_string(),
+                scope: None,
             },
         ];
 
@@ -340,6 +1220,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_todo_file_missing_is_empty() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todos = read_todo_file(&temp_dir.path().join("TODO.md")).unwrap();
+        assert!(todos.is_empty());
+    }
+
     #[test]
     fn test_read_todo_file_with_markdown_parser() {
         init_logger();
@@ -371,6 +1259,7 @@ mod tests {
                 line_number: 12,
                 message: "Refactor this function".to_string(),
                 marker: "TODO".to_string(),
+                scope: None,
             }
         );
         assert_eq!(
@@ -380,6 +1269,7 @@ mod tests {
                 line_number: 5,
                 message: "Add error handling".to_string(),
                 marker: "TODO".to_string(),
+                scope: None,
             }
         );
     }
@@ -397,18 +1287,21 @@ mod tests {
                 line_number: 20,
                 message: "Fix bug in foo".to_string(),
                 marker: "Fix".to_string(),
+                scope: None,
             },
             MarkedItem {
                 file_path: PathBuf::from("src/bar.rs"),
                 line_number: 10,
                 message: "Refactor bar".to_string(),
                 marker: "Refactor".to_string(),
+                scope: None,
             },
             MarkedItem {
                 file_path: PathBuf::from("src/foo.rs"),
                 line_number: 30,
                 message: "Add tests for foo".to_string(),
                 marker: "Add".to_string(),
+                scope: None,
             },
         ];
 
@@ -452,4 +1345,736 @@ mod tests {
             "Marker section ordering is incorrect"
         );
     }
+
+    #[test]
+    fn test_write_todo_file_links_relative_to_todo_dir() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            MarkedItem {
+                file_path: PathBuf::from("src/main.rs"),
+                line_number: 3,
+                message: "Wire up config".to_string(),
+                marker: "TODO".to_string(),
+                scope: None,
+            },
+            MarkedItem {
+                file_path: PathBuf::from("docs/guide/intro.rs"),
+                line_number: 7,
+                message: "Explain setup".to_string(),
+                marker: "TODO".to_string(),
+                scope: None,
+            },
+        ];
+        let options = WriteOptions {
+            link_base: Some(PathBuf::from("docs/guide")),
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items, &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(content.contains("* [src/main.rs:3](../../src/main.rs#L3): Wire up config"));
+        assert!(content.contains("* [docs/guide/intro.rs:7](intro.rs#L7): Explain setup"));
+        // Headers stay repo-relative so the file can be read back.
+        assert!(content.contains("## src/main.rs"));
+        let parsed = read_todo_file(&todo_path).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].file_path, PathBuf::from("src/main.rs"));
+    }
+
+    #[test]
+    fn test_line_anchor_bitbucket_round_trip() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![MarkedItem {
+            file_path: PathBuf::from("src/main.rs"),
+            line_number: 12,
+            message: "Call parse(): handle errors".to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        }];
+        let options = WriteOptions {
+            line_anchor: Some("#lines-{line}".to_string()),
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(content
+            .contains("* [src/main.rs:12](src/main.rs#lines-12): Call parse(): handle errors"));
+        assert_eq!(read_todo_file(&todo_path).unwrap(), items);
+    }
+
+    #[test]
+    fn test_scope_round_trip() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            MarkedItem {
+                file_path: PathBuf::from("src/main.rs"),
+                line_number: 4,
+                message: "Wire up config".to_string(),
+                marker: "TODO".to_string(),
+                scope: Some("main".to_string()),
+            },
+            MarkedItem {
+                file_path: PathBuf::from("src/main.rs"),
+                line_number: 9,
+                message: "Top-level note".to_string(),
+                marker: "TODO".to_string(),
+                scope: None,
+            },
+        ];
+        write_todo_file(&todo_path, items.clone()).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(content.contains("* [src/main.rs:4](src/main.rs#L4) in `main`: Wire up config"));
+        assert!(content.contains("* [src/main.rs:9](src/main.rs#L9): Top-level note"));
+        assert_eq!(read_todo_file(&todo_path).unwrap(), items);
+    }
+
+    #[test]
+    fn test_no_links_round_trip() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item("src/lib.rs", 3, "TODO", "Shared cleanup"),
+            item("src/main.rs", 3, "TODO", "Shared cleanup"),
+            item(
+                "src/main.rs",
+                12,
+                "TODO",
+                "Retry at 10:30: the server restarts",
+            ),
+        ];
+        let options = WriteOptions {
+            no_links: true,
+            dedup_global: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert_eq!(
+            content,
+            "# TODO\n\
+             * Shared cleanup\n  \
+             * src/lib.rs:3\n  \
+             * src/main.rs:3\n\
+             \n\
+             ## src/main.rs\n\
+             * src/main.rs:12: Retry at 10:30: the server restarts\n"
+        );
+        assert_eq!(read_todo_file(&todo_path).unwrap(), items);
+    }
+
+    #[test]
+    fn test_sort_files_by_count() {
+        init_logger();
+        let items = vec![
+            item("src/a.rs", 1, "TODO", "item 1"),
+            item("src/b.rs", 1, "TODO", "item 1"),
+            item("src/b.rs", 2, "TODO", "item 2"),
+            item("src/b.rs", 3, "TODO", "item 3"),
+            item("src/c.rs", 1, "TODO", "item 1"),
+            item("src/d.rs", 1, "TODO", "item 1"),
+            item("src/d.rs", 2, "TODO", "item 2"),
+            item("src/d.rs", 3, "TODO", "item 3"),
+        ];
+        let options = WriteOptions {
+            sort_files_by_count: true,
+            ..Default::default()
+        };
+        let content = render_todo_file(items, &options);
+
+        let headers: Vec<&str> = content
+            .lines()
+            .filter(|line| line.starts_with("## "))
+            .collect();
+        assert_eq!(
+            headers,
+            ["## src/b.rs", "## src/d.rs", "## src/a.rs", "## src/c.rs"]
+        );
+    }
+
+    #[test]
+    fn test_flat_round_trip() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item(
+                "src/main.rs",
+                12,
+                "TODO",
+                "Retry at 10:30: the server restarts",
+            ),
+            item("src/lib.rs", 3, "FIXME", "Handle overflow"),
+            item("src/main.rs", 4, "TODO", "Parse flags"),
+        ];
+        let options = WriteOptions {
+            flat: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items, &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert_eq!(
+            content,
+            "src/lib.rs:3: [FIXME] Handle overflow\n\
+             src/main.rs:4: [TODO] Parse flags\n\
+             src/main.rs:12: [TODO] Retry at 10:30: the server restarts\n"
+        );
+        let read = read_todo_file(&todo_path).unwrap();
+        assert_eq!(read.len(), 3);
+        assert_eq!(read[0], item("src/lib.rs", 3, "FIXME", "Handle overflow"));
+        assert_eq!(
+            read[2],
+            item(
+                "src/main.rs",
+                12,
+                "TODO",
+                "Retry at 10:30: the server restarts"
+            )
+        );
+    }
+
+    #[test]
+    fn test_sync_todo_file_sequential_merges_keep_disjoint_sections() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        // Absolute paths so the existence filter doesn't depend on the cwd.
+        let job_a_file = temp_dir.path().join("a.rs");
+        let job_b_file = temp_dir.path().join("b.py");
+        fs::write(&job_a_file, "// TODO: from job a\n").unwrap();
+        fs::write(&job_b_file, "# TODO: from job b\n").unwrap();
+        let job_a = job_a_file.to_str().unwrap();
+        let job_b = job_b_file.to_str().unwrap();
+
+        // Each job only scanned its own file; TODO.md doesn't exist yet for the first.
+        sync_todo_file(
+            &todo_path,
+            vec![item(job_a, 1, "TODO", "from job a")],
+            vec![job_a_file.clone()],
+        )
+        .unwrap();
+        sync_todo_file(
+            &todo_path,
+            vec![item(job_b, 1, "TODO", "from job b")],
+            vec![job_b_file.clone()],
+        )
+        .unwrap();
+
+        let merged = read_todo_file(&todo_path).unwrap();
+        assert_eq!(merged.len(), 2);
+        assert!(merged.contains(&item(job_a, 1, "TODO", "from job a")));
+        assert!(merged.contains(&item(job_b, 1, "TODO", "from job b")));
+    }
+
+    #[test]
+    fn test_sync_todo_file_keep_reviewed() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let a = temp_dir.path().join("a.rs");
+        let b = temp_dir.path().join("b.rs");
+        fs::write(&a, "fn a() {}\n").unwrap();
+        fs::write(&b, "// TODO: keep me\n").unwrap();
+        let (a_file, b_file) = (a.to_str().unwrap(), b.to_str().unwrap());
+        let seed = || {
+            write_todo_file(
+                &todo_path,
+                vec![
+                    item(a_file, 1, "TODO", "remove me"),
+                    item(b_file, 1, "TODO", "keep me"),
+                ],
+            )
+            .unwrap()
+        };
+        let placeholder = format!("## {} — (none)", a.display());
+        let options = WriteOptions {
+            keep_reviewed: true,
+            ..Default::default()
+        };
+
+        // Without the flag, a.rs's section disappears with its last TODO.
+        seed();
+        sync_todo_file(&todo_path, vec![], vec![a.clone()]).unwrap();
+        assert!(!fs::read_to_string(&todo_path)
+            .unwrap()
+            .contains(&placeholder));
+
+        // With it, a placeholder remains...
+        seed();
+        sync_todo_file_with_options(&todo_path, vec![], vec![a.clone()], &options).unwrap();
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(content.contains(&placeholder), "{content}");
+        assert_eq!(
+            read_todo_file(&todo_path).unwrap(),
+            vec![item(b_file, 1, "TODO", "keep me")]
+        );
+
+        // ...survives syncs of other files...
+        sync_todo_file_with_options(
+            &todo_path,
+            vec![item(b_file, 1, "TODO", "keep me")],
+            vec![b.clone()],
+            &options,
+        )
+        .unwrap();
+        assert!(fs::read_to_string(&todo_path)
+            .unwrap()
+            .contains(&placeholder));
+
+        // ...and is replaced once the file has TODOs again.
+        sync_todo_file_with_options(
+            &todo_path,
+            vec![item(a_file, 1, "TODO", "new")],
+            vec![a.clone()],
+            &options,
+        )
+        .unwrap();
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(!content.contains(&placeholder), "{content}");
+        assert!(content.contains(": new"));
+    }
+
+    #[test]
+    fn test_write_todo_file_dedup_global() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item("gen/b.rs", 3, "TODO", "Regenerate from the template"),
+            item("gen/a.rs", 3, "TODO", "Regenerate from the template"),
+            item("src/main.rs", 8, "TODO", "Parse flags"),
+        ];
+        let options = WriteOptions {
+            dedup_global: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        let expected = "\
+# TODO
+* Regenerate from the template
+  * [gen/a.rs:3](gen/a.rs#L3)
+  * [gen/b.rs:3](gen/b.rs#L3)
+
+## src/main.rs
+* [src/main.rs:8](src/main.rs#L8): Parse flags
+";
+        assert_eq!(content, expected);
+
+        // Every occurrence is read back as its own item.
+        let mut parsed = read_todo_file(&todo_path).unwrap();
+        parsed.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        let mut items = items;
+        items.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        assert_eq!(parsed, items);
+    }
+
+    #[test]
+    fn test_validate_rejects_mangled_entry() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        // A broken link must not pass as a `--dedup-global` message bullet.
+        fs::write(&todo_path, "# TODO\n## x.rs\n* [x.rs:1(x.rs#L1): msg\n").unwrap();
+        assert!(!validate_todo_file(&todo_path));
+
+        // A message bullet with its locations below it is fine.
+        fs::write(&todo_path, "# TODO\n* msg\n  * [x.rs:1](x.rs#L1)\n").unwrap();
+        assert!(validate_todo_file(&todo_path));
+    }
+
+    #[test]
+    fn test_write_todo_file_group_by_assignee() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item("src/b.rs", 4, "TODO", "@bob: add retries"),
+            item("src/a.rs", 1, "TODO", "@alice split this module"),
+            item("src/a.rs", 9, "TODO", "tidy up"),
+            item("src/b.rs", 2, "FIXME", "@alice handle overflow"),
+        ];
+        let options = WriteOptions {
+            group_by_assignee: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert_eq!(
+            content,
+            "## @alice\n\
+             ### FIXME\n\
+             * [src/b.rs:2](src/b.rs#L2): @alice handle overflow\n\
+             ### TODO\n\
+             * [src/a.rs:1](src/a.rs#L1): @alice split this module\n\
+             \n\
+             ## @bob\n\
+             ### TODO\n\
+             * [src/b.rs:4](src/b.rs#L4): @bob: add retries\n\
+             \n\
+             ## (unassigned)\n\
+             ### TODO\n\
+             * [src/a.rs:9](src/a.rs#L9): tidy up\n"
+        );
+
+        // The grouped layout reads back into the same items.
+        let mut parsed = read_todo_file(&todo_path).unwrap();
+        parsed.sort_by_key(|item| item.line_number);
+        let mut items = items;
+        items.sort_by_key(|item| item.line_number);
+        assert_eq!(parsed, items);
+    }
+
+    #[test]
+    fn test_write_todo_file_timestamp() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![MarkedItem {
+            file_path: PathBuf::from("src/a.rs"),
+            line_number: 1,
+            message: "Wire up config".to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        }];
+        let options = WriteOptions {
+            timestamp: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        let (first, rest) = content.split_once('\n').unwrap();
+        let time = first
+            .strip_prefix("<!-- generated: ")
+            .and_then(|l| l.strip_suffix(" -->"))
+            .expect("timestamp line");
+        assert!(chrono::DateTime::parse_from_rfc3339(time).is_ok(), "{time}");
+        assert_eq!(
+            rest,
+            "# TODO\n## src/a.rs\n* [src/a.rs:1](src/a.rs#L1): Wire up config\n"
+        );
+
+        // The timestamp line is ignored when reading the file back...
+        assert_eq!(read_todo_file(&todo_path).unwrap(), items);
+        // ...and a rewrite with unchanged items leaves the file alone.
+        let stale = content.replacen(time, "2000-01-01T00:00:00Z", 1);
+        fs::write(&todo_path, &stale).unwrap();
+        write_todo_file_with_options(&todo_path, items, &options).unwrap();
+        assert_eq!(fs::read_to_string(&todo_path).unwrap(), stale);
+    }
+
+    #[test]
+    fn test_write_todo_file_summary_header() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item("src/a.rs", 1, "TODO", "message 1"),
+            item("src/a.rs", 2, "TODO", "message 2"),
+            item("src/b.rs", 3, "TODO", "message 3"),
+            item("src/b.rs", 4, "FIXME", "message 4"),
+        ];
+        let options = WriteOptions {
+            summary_header: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(content.starts_with("<!-- 1 FIXME, 3 TODO -->\n# FIXME\n"));
+
+        // The summary line is ignored when reading the file back.
+        assert!(validate_todo_file(&todo_path));
+        let mut parsed = read_todo_file(&todo_path).unwrap();
+        parsed.sort_by_key(|item| item.line_number);
+        assert_eq!(parsed, items);
+    }
+
+    #[test]
+    fn test_rewrite_line_numbers() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+        // Hand-ordered entries: the rewrite must keep this order.
+        let content = "\
+# TODO
+## src/a.rs
+* [src/a.rs:9](src/a.rs#L9): second thing
+* [src/a.rs:4](src/a.rs#L4): Fix the parser.
+* [src/a.rs:20](src/a.rs#L20): gone
+";
+        fs::write(&todo_path, content).unwrap();
+
+        // Three lines were inserted above both comments, and one message
+        // was reworded slightly.
+        let current = vec![
+            item("src/a.rs", 7, "TODO", "fix the parser"),
+            item("src/a.rs", 12, "TODO", "second thing"),
+        ];
+        assert_eq!(rewrite_line_numbers(&todo_path, &current).unwrap(), 2);
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert_eq!(
+            content,
+            "\
+# TODO
+## src/a.rs
+* [src/a.rs:12](src/a.rs#L12): second thing
+* [src/a.rs:7](src/a.rs#L7): Fix the parser.
+* [src/a.rs:20](src/a.rs#L20): gone
+"
+        );
+
+        // Nothing left to move: the file is not rewritten.
+        assert_eq!(rewrite_line_numbers(&todo_path, &current).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_project_roots_links() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item("projects/api/src/main.rs", 3, "TODO", "message 3"),
+            item("projects/web/app.js", 5, "TODO", "message 5"),
+            item("tools/build.rs", 7, "TODO", "message 7"),
+        ];
+        let options = WriteOptions {
+            project_roots: vec![PathBuf::from("projects/api"), PathBuf::from("projects/web")],
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert!(content.contains("* [projects/api/src/main.rs:3](src/main.rs#L3): message 3"));
+        assert!(content.contains("* [projects/web/app.js:5](app.js#L5): message 5"));
+        assert!(content.contains("* [tools/build.rs:7](tools/build.rs#L7): message 7"));
+
+        let mut parsed = read_todo_file(&todo_path).unwrap();
+        parsed.sort_by_key(|item| item.line_number);
+        assert_eq!(parsed, items);
+    }
+
+    #[test]
+    fn test_no_sort_keeps_discovery_order() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![
+            item("src/z.rs", 8, "TODO", "message 8"),
+            item("src/z.rs", 2, "TODO", "message 2"),
+            item("src/a.rs", 5, "FIXME", "message 5"),
+            item("src/a.rs", 1, "TODO", "message 1"),
+        ];
+        let options = WriteOptions {
+            no_sort: true,
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert_eq!(
+            content,
+            "\
+# TODO
+## src/z.rs
+* [src/z.rs:8](src/z.rs#L8): message 8
+* [src/z.rs:2](src/z.rs#L2): message 2
+
+## src/a.rs
+* [src/a.rs:1](src/a.rs#L1): message 1
+# FIXME
+## src/a.rs
+* [src/a.rs:5](src/a.rs#L5): message 5
+"
+        );
+    }
+
+    #[test]
+    fn test_diff_entries() {
+        let before = vec![
+            item("a.rs", 1, "TODO", "kept"),
+            item("a.rs", 5, "TODO", "moved"),
+            item("b.rs", 2, "TODO", "fixed"),
+        ];
+        let after = vec![
+            item("a.rs", 1, "TODO", "kept"),
+            item("a.rs", 9, "TODO", "moved"),
+            item("b.rs", 4, "TODO", "new"),
+        ];
+        let (added, removed) = diff_entries(&before, &after);
+        assert_eq!(added, vec![item("b.rs", 4, "TODO", "new")]);
+        assert_eq!(removed, vec![item("b.rs", 2, "TODO", "fixed")]);
+    }
+
+    #[test]
+    fn test_preamble_round_trip() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+
+        let items = vec![MarkedItem {
+            file_path: PathBuf::from("src/main.rs"),
+            line_number: 3,
+            message: "Parse flags".to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        }];
+        let options = WriteOptions {
+            preamble: Some(
+                "Auto-generated — do not edit manually.\n\n# Not a marker\n".to_string(),
+            ),
+            ..Default::default()
+        };
+        write_todo_file_with_options(&todo_path, items.clone(), &options).unwrap();
+
+        let content = fs::read_to_string(&todo_path).unwrap();
+        assert_eq!(
+            content,
+            "\
+<!-- preamble -->
+Auto-generated — do not edit manually.
+
+# Not a marker
+<!-- end preamble -->
+
+# TODO
+## src/main.rs
+* [src/main.rs:3](src/main.rs#L3): Parse flags
+"
+        );
+        assert!(validate_todo_file(&todo_path));
+        assert_eq!(read_todo_file(&todo_path).unwrap(), items);
+
+        // Syncing keeps the preamble and doesn't rewrite an unchanged file.
+        sync_todo_file_with_options(
+            &todo_path,
+            items.clone(),
+            vec![PathBuf::from("src/main.rs")],
+            &options,
+        )
+        .unwrap();
+        assert_eq!(fs::read_to_string(&todo_path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_dedupe_items_modes() {
+        let items = vec![
+            item("src/a.rs", 1, "TODO", "Handle errors"),
+            item("src/a.rs", 1, "TODO", "Handle errors"),
+            item("src/a.rs", 1, "FIXME", "Handle errors"),
+            item("src/b.rs", 4, "TODO", "Handle errors"),
+            item("src/b.rs", 4, "TODO", "Log the request"),
+        ];
+        let kept = |by| {
+            dedupe_items(items.clone(), by)
+                .iter()
+                .map(|i| {
+                    format!(
+                        "{}:{} {} {}",
+                        i.file_path.display(),
+                        i.line_number,
+                        i.marker,
+                        i.message
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(dedupe_items(items.clone(), DedupeBy::None), items);
+        assert_eq!(
+            kept(DedupeBy::Message),
+            [
+                "src/a.rs:1 TODO Handle errors",
+                "src/b.rs:4 TODO Log the request"
+            ]
+        );
+        assert_eq!(
+            kept(DedupeBy::Line),
+            [
+                "src/a.rs:1 TODO Handle errors",
+                "src/b.rs:4 TODO Handle errors"
+            ]
+        );
+        assert_eq!(
+            kept(DedupeBy::Full),
+            [
+                "src/a.rs:1 TODO Handle errors",
+                "src/a.rs:1 FIXME Handle errors",
+                "src/b.rs:4 TODO Handle errors",
+                "src/b.rs:4 TODO Log the request",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stable_ids_survive_line_changes() {
+        init_logger();
+        let temp_dir = tempdir().unwrap();
+        let todo_path = temp_dir.path().join("TODO.md");
+        let options = WriteOptions {
+            stable_ids: true,
+            ..Default::default()
+        };
+        let id_re = Regex::new(r"\[T:([0-9a-f]{6})\] Parse flags").unwrap();
+
+        write_todo_file_with_options(
+            &todo_path,
+            vec![item("src/main.rs", 3, "TODO", "Parse flags")],
+            &options,
+        )
+        .unwrap();
+        let first = fs::read_to_string(&todo_path).unwrap();
+        let first_id = id_re.captures(&first).expect("entry has an ID")[1].to_string();
+        assert_eq!(
+            read_todo_file(&todo_path).unwrap(),
+            vec![item("src/main.rs", 3, "TODO", "Parse flags")]
+        );
+
+        // Lines were inserted above the TODO; the next run moves it.
+        sync_todo_file_with_options(
+            &todo_path,
+            vec![item("src/main.rs", 7, "TODO", "Parse flags")],
+            vec![PathBuf::from("src/main.rs")],
+            &options,
+        )
+        .unwrap();
+        let second = fs::read_to_string(&todo_path).unwrap();
+        assert!(second.contains("src/main.rs:7"), "{second}");
+        assert_eq!(
+            id_re.captures(&second).expect("entry has an ID")[1],
+            first_id
+        );
+        assert_eq!(
+            read_todo_file(&todo_path).unwrap(),
+            vec![item("src/main.rs", 7, "TODO", "Parse flags")]
+        );
+    }
 }
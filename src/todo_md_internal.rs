@@ -1,6 +1,6 @@
 use crate::MarkedItem;
 use log::{debug, info};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 // TODO: generalize in maker collection
@@ -64,6 +64,38 @@ impl TodoCollection {
         });
         all_items
     }
+
+    /// Returns all MarkedItem entries file by file, following `order` (the
+    /// first occurrence of each path counts) and keeping each file's items
+    /// as they were added. Files missing from `order` come last, by path.
+    pub fn to_ordered_vec(&self, order: &[PathBuf]) -> Vec<MarkedItem> {
+        let mut seen = HashSet::new();
+        let mut rest: Vec<&PathBuf> = self
+            .todos
+            .keys()
+            .filter(|file| !order.contains(file))
+            .collect();
+        rest.sort();
+        order
+            .iter()
+            .chain(rest)
+            .filter(|file| seen.insert(*file))
+            .flat_map(|file| self.todos.get(file).into_iter().flatten().cloned())
+            .collect()
+    }
+
+    /// Returns `(file, count)` for every file holding more than `threshold`
+    /// items, sorted by file path so reports are deterministic.
+    pub fn files_exceeding(&self, threshold: usize) -> Vec<(PathBuf, usize)> {
+        let mut dense: Vec<(PathBuf, usize)> = self
+            .todos
+            .iter()
+            .filter(|(_, items)| items.len() > threshold)
+            .map(|(file, items)| (file.clone(), items.len()))
+            .collect();
+        dense.sort();
+        dense
+    }
 }
 
 impl Default for TodoCollection {
@@ -76,6 +108,7 @@ impl Default for TodoCollection {
 mod tests {
     use super::*;
     use crate::test_utils::init_logger;
+    use crate::todo_md::tests::item;
     use crate::MarkedItem;
     use std::path::PathBuf;
 
@@ -88,6 +121,7 @@ mod tests {
             line_number: 42,
             message: "Test TODO".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         collection.add_item(item.clone());
         assert!(collection.todos.contains_key(&PathBuf::from("src/test.rs")));
@@ -106,6 +140,7 @@ mod tests {
             line_number: 10,
             message: "Fix bug".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(item1.clone());
 
@@ -115,6 +150,7 @@ mod tests {
             line_number: 20,
             message: "Implement new feature".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col2.add_item(item1.clone());
         col2.add_item(item2.clone());
@@ -138,6 +174,7 @@ mod tests {
             line_number: 15,
             message: "Refactor code".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(item.clone());
 
@@ -162,6 +199,7 @@ mod tests {
             line_number: 25,
             message: "Optimize performance".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(item.clone());
 
@@ -184,6 +222,7 @@ mod tests {
             line_number: 5,
             message: "Improve variable naming".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(item1.clone());
 
@@ -193,6 +232,7 @@ mod tests {
             line_number: 10,
             message: "Add unit tests".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col2.add_item(item2.clone());
 
@@ -219,18 +259,21 @@ mod tests {
             line_number: 50,
             message: "Last item".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let item2 = MarkedItem {
             file_path: PathBuf::from("src/a.rs"),
             line_number: 10,
             message: "First item".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let item3 = MarkedItem {
             file_path: PathBuf::from("src/a.rs"),
             line_number: 20,
             message: "Second item".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         // Add items in non-sorted order.
         collection.add_item(item1.clone());
@@ -254,6 +297,7 @@ mod tests {
             line_number: 10,
             message: "Fix bug".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(item1.clone());
 
@@ -263,12 +307,14 @@ mod tests {
             line_number: 20,
             message: "Implement feature".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let item3 = MarkedItem {
             file_path: PathBuf::from("src/foo.rs"),
             line_number: 30,
             message: "Add tests".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col2.add_item(item2.clone());
         col2.add_item(item3.clone());
@@ -294,18 +340,21 @@ mod tests {
             line_number: 50,
             message: "Last item".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let item2 = MarkedItem {
             file_path: PathBuf::from("src/a.rs"),
             line_number: 10,
             message: "First item".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let item3 = MarkedItem {
             file_path: PathBuf::from("src/a.rs"),
             line_number: 20,
             message: "Second item".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         collection.add_item(item1.clone());
         collection.add_item(item2.clone());
@@ -328,12 +377,14 @@ mod tests {
             line_number: 10,
             message: "Fix bug".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let item_stale = MarkedItem {
             file_path: PathBuf::from("src/foo.rs"),
             line_number: 15,
             message: "Old note".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(item_old);
         col1.add_item(item_stale);
@@ -344,6 +395,7 @@ mod tests {
             line_number: 20,
             message: "Implement feature".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col2.add_item(item_new.clone());
 
@@ -370,12 +422,14 @@ mod tests {
             line_number: 5,
             message: "A: initial task".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         let a_item2 = MarkedItem {
             file_path: PathBuf::from("src/a.rs"),
             line_number: 15,
             message: "A: old task".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(a_item1);
         col1.add_item(a_item2);
@@ -386,6 +440,7 @@ mod tests {
             line_number: 10,
             message: "B: fix issue".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(b_item1.clone());
 
@@ -395,6 +450,7 @@ mod tests {
             line_number: 20,
             message: "C: temporary note".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col1.add_item(c_item1);
 
@@ -406,6 +462,7 @@ mod tests {
             line_number: 7,
             message: "A: new task".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col2.add_item(a_item_new.clone());
 
@@ -415,6 +472,7 @@ mod tests {
             line_number: 12,
             message: "B: additional improvement".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         // Note: Even though b_item1 is already in col1, intended behavior is to replace the list.
         col2.add_item(b_item1.clone());
@@ -426,6 +484,7 @@ mod tests {
             line_number: 1,
             message: "D: start here".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         col2.add_item(d_item1.clone());
 
@@ -468,6 +527,7 @@ mod tests {
             line_number: 100,
             message: "Obsolete TODO".to_string(),
             marker: "TODO".to_string(),
+            scope: None,
         };
         original.add_item(item);
 
@@ -483,4 +543,53 @@ mod tests {
             "Expected 'src/old.rs' to be removed when no new TODOs are provided."
         );
     }
+
+    #[test]
+    fn test_files_exceeding_threshold() {
+        init_logger();
+        let mut collection = TodoCollection::new();
+        for line_number in [1, 5, 9] {
+            collection.add_item(MarkedItem {
+                file_path: PathBuf::from("src/busy.rs"),
+                line_number,
+                message: "Busy".to_string(),
+                marker: "TODO".to_string(),
+                scope: None,
+            });
+        }
+        collection.add_item(MarkedItem {
+            file_path: PathBuf::from("src/quiet.rs"),
+            line_number: 2,
+            message: "Quiet".to_string(),
+            marker: "TODO".to_string(),
+            scope: None,
+        });
+
+        assert_eq!(
+            collection.files_exceeding(2),
+            vec![(PathBuf::from("src/busy.rs"), 3)]
+        );
+        assert!(collection.files_exceeding(3).is_empty());
+        assert_eq!(collection.files_exceeding(0).len(), 2);
+    }
+
+    #[test]
+    fn test_to_ordered_vec() {
+        init_logger();
+        let mut collection = TodoCollection::new();
+        for (file, line) in [("b.rs", 9), ("b.rs", 2), ("a.rs", 1), ("c.rs", 4)] {
+            collection.add_item(item(file, line, "TODO", &format!("message {line}")));
+        }
+
+        let ordered = collection.to_ordered_vec(&[PathBuf::from("c.rs"), PathBuf::from("b.rs")]);
+        assert_eq!(
+            ordered,
+            vec![
+                item("c.rs", 4, "TODO", "message 4"),
+                item("b.rs", 9, "TODO", "message 9"),
+                item("b.rs", 2, "TODO", "message 2"),
+                item("a.rs", 1, "TODO", "message 1")
+            ]
+        );
+    }
 }
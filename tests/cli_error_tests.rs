@@ -213,3 +213,33 @@ Just plain text that should trigger validation failure
 
     info!("Test completed: test_sync_todo_file_fallback_mechanism");
 }
+
+#[test]
+fn test_empty_todos_warn_fail_writes_then_fails() {
+    init_logger();
+    info!("Starting test: test_empty_todos_warn_fail_writes_then_fails");
+
+    let (temp_dir, _repo) = init_repo().expect("Failed to initialize test repo");
+    let repo_dir = temp_dir.path();
+    let file_path = repo_dir.join("main.rs");
+    fs::write(&file_path, "// TODO: Parse flags\n// TODO:\n").expect("failed to write file");
+
+    let mut cmd =
+        Command::cargo_bin("rusty-todo-md").expect("failed to locate rusty-todo-md binary");
+    cmd.current_dir(repo_dir)
+        .arg("--todo-path")
+        .arg("TODO.md")
+        .arg("--empty-todos")
+        .arg("warn-fail")
+        .arg(file_path.to_str().expect("file path valid"));
+
+    cmd.assert()
+        .failure()
+        .stderr(contains("empty TODO comment found"));
+
+    // TODO.md was still written, without the empty entry.
+    let content = fs::read_to_string(repo_dir.join("TODO.md")).expect("TODO.md written");
+    debug!("TODO.md content:\n{content}");
+    assert!(content.contains("Parse flags"));
+    assert!(!content.contains(":2]"));
+}
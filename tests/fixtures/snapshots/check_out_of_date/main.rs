@@ -0,0 +1,4 @@
+// TODO: first
+fn main() {
+    // FIXME: second
+}
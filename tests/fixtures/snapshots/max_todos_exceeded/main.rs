@@ -0,0 +1,5 @@
+// TODO: first
+// TODO: second
+fn main() {
+    // FIXME: third
+}
@@ -0,0 +1,3 @@
+fn main() {
+    // Nothing left to do here.
+}
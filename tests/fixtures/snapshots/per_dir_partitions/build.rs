@@ -0,0 +1,2 @@
+// FIXME: Pin the toolchain
+fn main() {}
@@ -0,0 +1,2 @@
+// TODO: Expose a builder
+pub fn build() {}
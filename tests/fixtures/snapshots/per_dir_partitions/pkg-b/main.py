@@ -0,0 +1,2 @@
+# TODO: Read settings from env
+print("pkg-b")
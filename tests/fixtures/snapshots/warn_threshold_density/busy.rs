@@ -0,0 +1,5 @@
+// TODO: Split parsing out of main
+// TODO: Validate input
+fn main() {
+    // FIXME: Handle the error path
+}
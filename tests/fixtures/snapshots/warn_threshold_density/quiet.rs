@@ -0,0 +1,2 @@
+// TODO: Document this helper
+fn helper() {}
@@ -136,3 +136,68 @@ fn test_get_staged_files() {
     assert!(staged.contains(&PathBuf::from("test.txt")));
     info!("Completed test_get_staged_files");
 }
+
+/// Committed lines report their commit date; lines only in the working
+/// tree, and files HEAD doesn't know, are uncommitted (`None`).
+#[test]
+fn test_blame_line() {
+    init_logger();
+    let (temp_dir, repo) = init_repo().unwrap();
+    let path = temp_dir.path();
+
+    let mut file = File::options()
+        .append(true)
+        .open(path.join("test.txt"))
+        .unwrap();
+    writeln!(file, "// TODO: not committed yet").unwrap();
+    File::create(path.join("new.rs")).unwrap();
+
+    let committed = GitOps
+        .blame_line(&repo, Path::new("test.txt"), 1)
+        .unwrap()
+        .expect("line 1 is committed");
+    assert!(chrono::Utc::now() - committed < chrono::Duration::minutes(5));
+    assert_eq!(
+        GitOps.blame_line(&repo, Path::new("test.txt"), 2).unwrap(),
+        None
+    );
+    // Absolute paths inside the work tree work too.
+    assert!(GitOps
+        .blame_line(&repo, &path.join("test.txt"), 1)
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        GitOps.blame_line(&repo, Path::new("new.rs"), 1).unwrap(),
+        None
+    );
+}
+
+/// Messages come newest first and stop at the requested count.
+#[test]
+fn test_recent_commit_messages() {
+    init_logger();
+    let (temp_dir, repo) = init_repo().unwrap();
+
+    std::fs::write(temp_dir.path().join("test.txt"), "changed\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("test.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let parent = repo.head().unwrap().peel_to_commit().unwrap();
+    let sig = git2::Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(
+        Some("HEAD"),
+        &sig,
+        &sig,
+        "Fix parser\n\nCloses TODO src/x.rs:10\n",
+        &tree,
+        &[&parent],
+    )
+    .unwrap();
+
+    assert_eq!(
+        GitOps.recent_commit_messages(&repo, 5).unwrap(),
+        ["Fix parser\n\nCloses TODO src/x.rs:10\n", "initial commit"]
+    );
+    assert_eq!(GitOps.recent_commit_messages(&repo, 1).unwrap().len(), 1);
+}
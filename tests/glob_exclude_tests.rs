@@ -6,7 +6,10 @@ mod utils;
 /// These integration tests verify end-to-end CLI behavior with the exclusion flags.
 mod glob_exclude_tests {
     use crate::utils::{init_repo, FakeGitOps};
+    use assert_cmd::Command;
     use log::LevelFilter;
+    use predicates::prelude::PredicateBooleanExt;
+    use predicates::str::contains;
     use rusty_todo_md::cli::run_cli_with_args;
     use rusty_todo_md::logger;
     use std::fs;
@@ -144,4 +147,178 @@ mod glob_exclude_tests {
         );
         assert!(content.contains("lib.rs"), "lib.rs should be included");
     }
+
+    /// Integration test verifying patterns loaded with --exclude-from are applied
+    #[test]
+    fn test_glob_exclude_from_file() {
+        init_logger();
+        log::info!("Starting test_glob_exclude_from_file");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let file1 = create_test_file(repo_path, "src/schema.gen.rs", "// TODO: Generated");
+        let file2 = create_test_file(repo_path, "src/api.gen.rs", "// TODO: Also generated");
+        let file3 = create_test_file(repo_path, "src/main.rs", "// TODO: Main");
+        let exclude_file = create_test_file(
+            repo_path,
+            "shared-excludes.txt",
+            "# generated code\n\n*.gen.rs\n",
+        );
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--exclude-from".to_string(),
+            exclude_file.to_str().unwrap().to_string(),
+            file1.to_str().unwrap().to_string(),
+            file2.to_str().unwrap().to_string(),
+            file3.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let staged_files = vec![file1, file2, file3];
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, staged_files, vec![]);
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(
+            !content.contains(".gen.rs"),
+            "*.gen.rs files should be excluded"
+        );
+        assert!(
+            content.contains("src/main.rs"),
+            "src/main.rs should be included"
+        );
+    }
+
+    /// Integration test verifying --ignore-tests skips test code and composes
+    /// with user excludes
+    #[test]
+    fn test_ignore_tests_flag() {
+        init_logger();
+        log::info!("Starting test_ignore_tests_flag");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let file1 = create_test_file(repo_path, "tests/foo.rs", "// TODO: Test helper");
+        let file2 = create_test_file(repo_path, "src/foo.rs", "// TODO: Keep me");
+        let file3 = create_test_file(repo_path, "src/foo.gen.rs", "// TODO: Generated");
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--ignore-tests".to_string(),
+            "--exclude".to_string(),
+            "*.gen.rs".to_string(),
+            file1.to_str().unwrap().to_string(),
+            file2.to_str().unwrap().to_string(),
+            file3.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let staged_files = vec![file1, file2, file3];
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, staged_files, vec![]);
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(
+            !content.contains("tests/foo.rs"),
+            "tests/foo.rs should be excluded"
+        );
+        assert!(
+            !content.contains("foo.gen.rs"),
+            "user --exclude patterns should still apply"
+        );
+        assert!(
+            content.contains("src/foo.rs"),
+            "src/foo.rs should be included"
+        );
+    }
+
+    /// Integration test verifying --include keeps only the listed extensions
+    #[test]
+    fn test_include_extension_allowlist() {
+        init_logger();
+        log::info!("Starting test_include_extension_allowlist");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let file1 = create_test_file(repo_path, "src/lib.rs", "// TODO: Rust item");
+        let file2 = create_test_file(repo_path, "scripts/build.py", "# TODO: Python item");
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--include".to_string(),
+            "rs".to_string(),
+            file1.to_str().unwrap().to_string(),
+            file2.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let staged_files = vec![file1, file2];
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, staged_files, vec![]);
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(
+            content.contains("Rust item"),
+            "the .rs file should be scanned"
+        );
+        assert!(
+            !content.contains("Python item"),
+            "the .py file is not in --include"
+        );
+    }
+
+    /// `--explain-excludes` names the pattern that excluded each file.
+    #[test]
+    fn test_explain_excludes_reports_pattern() {
+        init_logger();
+        log::info!("Starting test_explain_excludes_reports_pattern");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let kept = create_test_file(repo_path, "src/main.rs", "// TODO: Main");
+        let excluded = create_test_file(repo_path, "build/gen.rs", "// TODO: Generated");
+
+        Command::cargo_bin("rusty-todo-md")
+            .expect("failed to locate rusty-todo-md binary")
+            .current_dir(repo_path)
+            .args([
+                "--exclude-dir",
+                "build",
+                "--exclude",
+                "*.log",
+                "--explain-excludes",
+                "--",
+            ])
+            .arg(&kept)
+            .arg(&excluded)
+            .assert()
+            .success()
+            .stderr(contains(format!(
+                "Excluding {} (matched build/)",
+                excluded.display()
+            )))
+            .stderr(contains("src/main.rs").not());
+    }
 }
@@ -2,8 +2,10 @@ mod utils;
 
 mod integration_tests {
     use crate::utils::{init_repo, FakeGitOps};
+    use assert_cmd::Command;
     use log::LevelFilter;
     use rusty_todo_md::cli::run_cli_with_args;
+    use rusty_todo_md::git_utils::GitOps;
     use rusty_todo_md::logger;
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -92,6 +94,39 @@ mod integration_tests {
         );
     }
 
+    /// A symlink passed alongside its target must not duplicate the target's TODOs.
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinked_file_scanned_once() {
+        init_logger();
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let real = create_test_file(repo_path, "real.rs", "// TODO: Only listed once");
+        let link = repo_path.join("link.rs");
+        std::os::unix::fs::symlink(&real, &link).expect("Failed to create symlink");
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            real.to_str().unwrap().to_string(),
+            link.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir, repo) = init_repo().expect("Failed to init repo");
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir, vec![real.clone()], vec![]);
+
+        run_cli_with_args(args, &fake_git_ops);
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        assert_eq!(content.matches("Only listed once").count(), 1);
+        // The first path given is the one shown.
+        assert!(content.contains("real.rs"));
+        assert!(!content.contains("link.rs"));
+    }
+
     /// Test running the CLI multiple times on the same file, simulating real-world updates.
     #[test]
     fn test_multiple_runs_update() {
@@ -447,4 +482,607 @@ mod integration_tests {
 
         log::info!("test_exclude_files_with_glob_patterns completed successfully");
     }
+
+    #[test]
+    fn test_since_days_drops_old_todos() {
+        init_logger();
+        log::info!("Starting test_since_days_drops_old_todos");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let file = create_test_file(
+            repo_path,
+            "src/main.rs",
+            "// TODO: Added this sprint\nfn main() {}\n// TODO: Left over from last year\n",
+        );
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--since-days".to_string(),
+            "14".to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+
+        let now = chrono::Utc::now();
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, vec![file.clone()], vec![])
+            .with_blame_date(file.clone(), 1, now - chrono::Duration::days(3))
+            .with_blame_date(file.clone(), 3, now - chrono::Duration::days(400));
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(
+            content.contains("Added this sprint"),
+            "recent TODO should be kept"
+        );
+        assert!(
+            !content.contains("Left over from last year"),
+            "TODO older than --since-days should be dropped"
+        );
+    }
+
+    #[test]
+    fn test_close_from_commits_removes_referenced_todos() {
+        init_logger();
+        log::info!("Starting test_close_from_commits_removes_referenced_todos");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let scanned = create_test_file(
+            repo_path,
+            "src/main.rs",
+            "// TODO: Still open\n// TODO: Done in the last commit\n",
+        );
+        let other = create_test_file(repo_path, "src/lib.rs", "// TODO: Closed too\n");
+        fs::write(
+            &todo_path,
+            format!(
+                "# TODO\n## {file}\n* [{file}:1]({file}#L1): Closed too\n",
+                file = other.display()
+            ),
+        )
+        .unwrap();
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--close-from-commits".to_string(),
+            "5".to_string(),
+            scanned.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, vec![scanned.clone()], vec![])
+            .with_commit_message("Handle empty input\n\nCloses TODO src/main.rs:2")
+            .with_commit_message("Split lib\n\ncloses todo src/lib.rs:1.");
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(content.contains("Still open"), "open TODO should be kept");
+        assert!(
+            !content.contains("Done in the last commit"),
+            "TODO referenced by a commit should be removed"
+        );
+        assert!(
+            !content.contains("Closed too"),
+            "entries of files not scanned should be closed as well"
+        );
+    }
+
+    #[test]
+    fn test_close_from_commits_keeps_other_markers_at_location() {
+        init_logger();
+        log::info!("Starting test_close_from_commits_keeps_other_markers_at_location");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let scanned = create_test_file(repo_path, "src/main.rs", "// TODO: Still open\n");
+        let other = create_test_file(repo_path, "src/lib.rs", "// TODO: Closed\n");
+        fs::write(
+            &todo_path,
+            format!(
+                "# FIXME\n## {file}\n* [{file}:1]({file}#L1): Not closed\n\n# TODO\n## {file}\n* [{file}:1]({file}#L1): Closed\n",
+                file = other.display()
+            ),
+        )
+        .unwrap();
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--close-from-commits".to_string(),
+            "5".to_string(),
+            scanned.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, vec![scanned.clone()], vec![])
+            .with_commit_message("Split lib\n\nCloses TODO src/lib.rs:1");
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(
+            !content.contains(": Closed"),
+            "the TODO named by the commit should be removed"
+        );
+        assert!(
+            content.contains("Not closed"),
+            "a FIXME at the same location should be kept"
+        );
+    }
+
+    #[test]
+    fn test_known_issues_excluded() {
+        init_logger();
+        log::info!("Starting test_known_issues_excluded");
+
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let repo_path = temp_dir.path();
+        let todo_path = repo_path.join("TODO.md");
+
+        let file = create_test_file(
+            repo_path,
+            "src/main.rs",
+            "// TODO: Not ticketed yet\nfn main() {}\n// TODO: Already has an issue\n",
+        );
+        let known_issues = repo_path.join("known-issues.txt");
+        fs::write(
+            &known_issues,
+            "# exported from the tracker\nsrc/main.rs:3\n",
+        )
+        .unwrap();
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--known-issues".to_string(),
+            known_issues.to_str().unwrap().to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let fake_git_ops = FakeGitOps::new(repo, temp_dir_git, vec![file.clone()], vec![]);
+
+        run_cli_with_args(args, &fake_git_ops);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        log::debug!("TODO.md content: {}", content);
+
+        assert!(
+            content.contains("Not ticketed yet"),
+            "TODO without an issue should be kept"
+        );
+        assert!(
+            !content.contains("Already has an issue"),
+            "TODO listed in --known-issues should be left out"
+        );
+    }
+
+    /// The test process runs in the crate's own checkout, so the repository
+    /// is only found through `--repo-path`.
+    #[test]
+    fn test_repo_path_outside_cwd() {
+        init_logger();
+        log::info!("Starting test_repo_path_outside_cwd");
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        assert_ne!(
+            std::env::current_dir().unwrap(),
+            repo_path,
+            "the test must run outside the repository"
+        );
+        let todo_path = repo_path.join("TODO.md");
+        let file = create_test_file(repo_path, "src/main.rs", "// TODO: Found via --repo-path\n");
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--repo-path".to_string(),
+            repo_path.to_str().unwrap().to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--auto-add".to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+
+        run_cli_with_args(args, &GitOps);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        assert!(content.contains("Found via --repo-path"));
+        let mut index = repo.index().unwrap();
+        index.read(true).unwrap();
+        assert!(
+            index.get_path(Path::new("TODO.md"), 0).is_some(),
+            "TODO.md should be staged in the repository given by --repo-path"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_links_updates_moved_line() {
+        init_logger();
+        log::info!("Starting test_rewrite_links_updates_moved_line");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let file = create_test_file(
+            repo_path,
+            "src/main.rs",
+            "fn main() {}\n// TODO: Moves down\n",
+        );
+
+        let scan = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+        run_cli_with_args(scan, &GitOps);
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        assert!(content.contains(":2]"), "unexpected TODO.md:\n{content}");
+
+        // Push the comment down by three lines without rescanning.
+        fs::write(
+            &file,
+            "use std::io;\n\n\nfn main() {}\n// TODO: Moves down\n",
+        )
+        .unwrap();
+
+        let rewrite = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--rewrite-links".to_string(),
+        ];
+        run_cli_with_args(rewrite, &GitOps);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        assert!(content.contains(":5]"), "unexpected TODO.md:\n{content}");
+        assert!(content.contains("#L5): Moves down"));
+        assert!(!content.contains(":2]"));
+    }
+
+    #[test]
+    fn test_severity_dir_routes_marker() {
+        init_logger();
+        log::info!("Starting test_severity_dir_routes_marker");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let file = create_test_file(
+            repo_path,
+            "src/lib.rs",
+            "// TODO: Add docs\n// FIXME: Handle overflow\n",
+        );
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--markers".to_string(),
+            "TODO".to_string(),
+            "FIXME".to_string(),
+            "--severity-dir".to_string(),
+            "FIXME=critical".to_string(),
+            "--".to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+        run_cli_with_args(args.clone(), &GitOps);
+
+        let root = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        assert!(root.contains("Add docs"));
+        assert!(!root.contains("Handle overflow"));
+        let critical_path = repo_path.join("critical").join("TODO.md");
+        let critical = fs::read_to_string(&critical_path).expect("Failed to read critical/TODO.md");
+        assert!(critical.contains("# FIXME"));
+        assert!(critical.contains("Handle overflow"));
+        assert!(!critical.contains("Add docs"));
+
+        // Fixing the FIXME empties critical/TODO.md on the next scan.
+        fs::write(&file, "// TODO: Add docs\n").unwrap();
+        run_cli_with_args(args, &GitOps);
+        let critical = fs::read_to_string(&critical_path).unwrap();
+        assert!(!critical.contains("Handle overflow"));
+    }
+
+    #[test]
+    fn test_summary_json_matches_todo_md() {
+        init_logger();
+        log::info!("Starting test_summary_json_matches_todo_md");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let summary_path = repo_path.join("todo-summary.json");
+        let a = create_test_file(
+            repo_path,
+            "src/a.rs",
+            "// TODO: First\n// TODO: Second\n// FIXME: Third\n",
+        );
+        let b = create_test_file(repo_path, "src/b.rs", "// TODO: Fourth\n");
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--summary-json".to_string(),
+            summary_path.to_str().unwrap().to_string(),
+            "--markers".to_string(),
+            "TODO".to_string(),
+            "FIXME".to_string(),
+            "--".to_string(),
+            a.to_str().unwrap().to_string(),
+            b.to_str().unwrap().to_string(),
+        ];
+        run_cli_with_args(args, &GitOps);
+
+        let summary: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&summary_path).unwrap()).unwrap();
+        let entries = rusty_todo_md::todo_md::read_todo_file(&todo_path).unwrap();
+        assert_eq!(summary["total"], entries.len());
+        for marker in ["TODO", "FIXME"] {
+            let count = entries.iter().filter(|e| e.marker == marker).count();
+            assert_eq!(summary["markers"][marker], count, "{marker}");
+        }
+        for file in [&a, &b] {
+            let count = entries.iter().filter(|e| &e.file_path == file).count();
+            assert_eq!(summary["files"][file.to_str().unwrap()], count);
+        }
+        assert_eq!(summary["markers"]["TODO"], 3);
+    }
+
+    #[test]
+    fn test_no_sort_preserves_argument_order() {
+        init_logger();
+        log::info!("Starting test_no_sort_preserves_argument_order");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let zeta = create_test_file(repo_path, "src/zeta.rs", "// TODO: Zeta first\n");
+        let alpha = create_test_file(repo_path, "src/alpha.rs", "// TODO: Alpha second\n");
+        let mid = create_test_file(repo_path, "src/mid.rs", "// TODO: Mid third\n");
+
+        let run = |files: &[&PathBuf]| {
+            let mut args = vec![
+                "rusty-todo-md".to_string(),
+                "--todo-path".to_string(),
+                todo_path.to_str().unwrap().to_string(),
+                "--no-sort".to_string(),
+                "--".to_string(),
+            ];
+            args.extend(files.iter().map(|f| f.to_str().unwrap().to_string()));
+            run_cli_with_args(args, &GitOps);
+            fs::read_to_string(&todo_path).expect("Failed to read TODO.md")
+        };
+        let position = |content: &str, needle: &str| content.find(needle).unwrap();
+
+        let content = run(&[&zeta, &alpha]);
+        assert!(position(&content, "Zeta first") < position(&content, "Alpha second"));
+
+        // A later scan keeps the existing order and appends the new file.
+        let content = run(&[&mid, &alpha]);
+        assert!(position(&content, "Zeta first") < position(&content, "Alpha second"));
+        assert!(position(&content, "Alpha second") < position(&content, "Mid third"));
+    }
+
+    #[test]
+    fn test_merge_strategy_refresh_all_updates_unscanned_file() {
+        init_logger();
+        log::info!("Starting test_merge_strategy_refresh_all_updates_unscanned_file");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let scanned = create_test_file(repo_path, "src/a.rs", "// TODO: In a\n");
+        let other = create_test_file(repo_path, "src/b.rs", "// TODO: In b\n");
+
+        let run = |files: &[&PathBuf], strategy: &str| {
+            let mut args = vec![
+                "rusty-todo-md".to_string(),
+                "--todo-path".to_string(),
+                todo_path.to_str().unwrap().to_string(),
+                "--merge-strategy".to_string(),
+                strategy.to_string(),
+                "--".to_string(),
+            ];
+            args.extend(files.iter().map(|f| f.to_str().unwrap().to_string()));
+            run_cli_with_args(args, &GitOps);
+            fs::read_to_string(&todo_path).expect("Failed to read TODO.md")
+        };
+        let b_label = |line: usize| format!("{}:{line}]", other.display());
+
+        run(&[&scanned, &other], "replace-scanned");
+        // b.rs's TODO moves down, but only a.rs is scanned.
+        fs::write(&other, "fn b() {}\n\n// TODO: In b\n").unwrap();
+
+        let content = run(&[&scanned], "replace-scanned");
+        assert!(
+            content.contains(&b_label(1)),
+            "stale entry expected:\n{content}"
+        );
+
+        let content = run(&[&scanned], "refresh-all");
+        assert!(
+            content.contains(&b_label(3)),
+            "unexpected TODO.md:\n{content}"
+        );
+        assert!(!content.contains(&b_label(1)));
+        assert!(content.contains("In a"));
+    }
+
+    #[test]
+    fn test_change_log_records_added_and_removed() {
+        init_logger();
+        log::info!("Starting test_change_log_records_added_and_removed");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let log_path = repo_path.join("todo-changes.log");
+        let file = create_test_file(repo_path, "src/main.rs", "// TODO: Old task\n");
+
+        let run = || {
+            let args = vec![
+                "rusty-todo-md".to_string(),
+                "--todo-path".to_string(),
+                todo_path.to_str().unwrap().to_string(),
+                "--change-log".to_string(),
+                log_path.to_str().unwrap().to_string(),
+                "--".to_string(),
+                file.to_str().unwrap().to_string(),
+            ];
+            run_cli_with_args(args, &GitOps);
+        };
+
+        run();
+        fs::write(&file, "fn main() {}\n// TODO: New task\n").unwrap();
+        run();
+        // Nothing changed: nothing is appended.
+        run();
+
+        let log = fs::read_to_string(&log_path).expect("Failed to read change log");
+        let lines: Vec<&str> = log.lines().collect();
+        let path = file.display();
+        assert_eq!(lines.len(), 5, "unexpected change log:\n{log}");
+        assert!(lines[0].ends_with(&format!(" {}", todo_path.display())));
+        assert_eq!(lines[1], format!("+ {path}:1: [TODO] Old task"));
+        assert!(lines[2].ends_with(&format!(" {}", todo_path.display())));
+        assert_eq!(lines[3], format!("+ {path}:2: [TODO] New task"));
+        assert_eq!(lines[4], format!("- {path}:1: [TODO] Old task"));
+    }
+
+    #[test]
+    fn test_uppercase_markers_merges_case_variants() {
+        init_logger();
+        log::info!("Starting test_uppercase_markers_merges_case_variants");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let file = create_test_file(
+            repo_path,
+            "src/lib.rs",
+            "// todo: lower\n// Todo: title\n// TODO: upper\n",
+        );
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--markers".to_string(),
+            "todo".to_string(),
+            "Todo".to_string(),
+            "TODO".to_string(),
+            "--uppercase-markers".to_string(),
+            "--".to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+        run_cli_with_args(args, &GitOps);
+
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        let headers: Vec<&str> = content.lines().filter(|l| l.starts_with("# ")).collect();
+        assert_eq!(headers, vec!["# TODO"]);
+        for message in ["lower", "title", "upper"] {
+            assert!(content.contains(message), "missing {message}:\n{content}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_paths_cleans_links() {
+        init_logger();
+        log::info!("Starting test_normalize_paths_cleans_links");
+
+        let (temp_dir_git, _repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        create_test_file(repo_path, "src/main.rs", "// TODO: Clean link\n");
+
+        // Relative paths are resolved against the working directory.
+        Command::cargo_bin("rusty-todo-md")
+            .expect("failed to locate rusty-todo-md binary")
+            .current_dir(repo_path)
+            .args(["--normalize-paths", "--", "./src/./main.rs"])
+            .assert()
+            .success();
+
+        let content =
+            fs::read_to_string(repo_path.join("TODO.md")).expect("Failed to read TODO.md");
+        assert!(
+            content.contains("## src/main.rs\n* [src/main.rs:1](src/main.rs#L1): Clean link"),
+            "unexpected TODO.md:\n{content}"
+        );
+    }
+
+    #[test]
+    fn test_annotate_writes_ids_into_source() {
+        init_logger();
+        log::info!("Starting test_annotate_writes_ids_into_source");
+
+        let (temp_dir_git, repo) = init_repo().expect("Failed to init repo");
+        let repo_path = temp_dir_git.path();
+        let todo_path = repo_path.join("TODO.md");
+        let file = create_test_file(
+            repo_path,
+            "src/main.rs",
+            "fn main() {}\n// TODO: Parse flags\n//   and env vars\n",
+        );
+
+        let args = vec![
+            "rusty-todo-md".to_string(),
+            "--todo-path".to_string(),
+            todo_path.to_str().unwrap().to_string(),
+            "--repo-path".to_string(),
+            repo_path.to_str().unwrap().to_string(),
+            "--annotate".to_string(),
+            "--auto-add".to_string(),
+            "--".to_string(),
+            file.to_str().unwrap().to_string(),
+        ];
+        run_cli_with_args(args.clone(), &GitOps);
+
+        let source = fs::read_to_string(&file).expect("Failed to read source");
+        let id_re = regex::Regex::new(r"// TODO\[T:([0-9a-f]{6})\]: Parse flags\n").unwrap();
+        let id = id_re.captures(&source).expect("comment has an ID")[1].to_string();
+        assert_eq!(
+            source,
+            format!("fn main() {{}}\n// TODO[T:{id}]: Parse flags\n//   and env vars\n")
+        );
+        let content = fs::read_to_string(&todo_path).expect("Failed to read TODO.md");
+        assert!(
+            content.contains(&format!(": [T:{id}] Parse flags and env vars")),
+            "TODO.md should show the same ID:\n{content}"
+        );
+        let mut index = repo.index().unwrap();
+        index.read(true).unwrap();
+        assert!(
+            index.get_path(Path::new("src/main.rs"), 0).is_some(),
+            "--auto-add should stage the annotated file"
+        );
+
+        // Already annotated: a second run changes neither file.
+        run_cli_with_args(args, &GitOps);
+        assert_eq!(fs::read_to_string(&file).unwrap(), source);
+        assert_eq!(fs::read_to_string(&todo_path).unwrap(), content);
+    }
 }
@@ -0,0 +1,176 @@
+use assert_cmd::Command;
+mod utils;
+use utils::init_repo;
+
+use std::fs;
+
+fn list_cmd(repo_dir: &std::path::Path, extra_args: &[&str]) -> Command {
+    let mut cmd =
+        Command::cargo_bin("rusty-todo-md").expect("failed to locate rusty-todo-md binary");
+    cmd.current_dir(repo_dir)
+        .env_remove("NO_COLOR")
+        .arg("--list")
+        .args(extra_args)
+        .args(["--markers", "TODO", "FIXME", "--", "lib.rs"]);
+    cmd
+}
+
+fn write_fixture(repo_dir: &std::path::Path) {
+    fs::write(
+        repo_dir.join("lib.rs"),
+        "// TODO: split this module\nfn f() {}\n// FIXME: handle overflow\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_list_respects_no_color() {
+    let (temp_dir, _repo) = init_repo().expect("Failed to initialize test repo");
+    write_fixture(temp_dir.path());
+
+    let output = list_cmd(temp_dir.path(), &[])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+
+    assert!(
+        !stdout.contains('\x1b'),
+        "unexpected escape codes: {stdout:?}"
+    );
+    assert_eq!(
+        stdout,
+        "FIXME (1)\n  lib.rs:3: handle overflow\nTODO (1)\n  lib.rs:1: split this module\n"
+    );
+    // --list never touches TODO.md.
+    assert!(!temp_dir.path().join("TODO.md").exists());
+}
+
+#[test]
+fn test_list_color_always() {
+    let (temp_dir, _repo) = init_repo().expect("Failed to initialize test repo");
+    write_fixture(temp_dir.path());
+
+    let output = list_cmd(temp_dir.path(), &["--color", "always"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+
+    assert!(
+        stdout.contains("\x1b["),
+        "expected escape codes: {stdout:?}"
+    );
+    assert!(stdout.contains("split this module"));
+}
+
+#[test]
+fn test_list_languages_outside_repo() {
+    // No git repository needed.
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    let output = Command::cargo_bin("rusty-todo-md")
+        .expect("failed to locate rusty-todo-md binary")
+        .current_dir(temp_dir.path())
+        .arg("--list-languages")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    for expected in ["rs", "py", "dockerfile", "Dockerfile"] {
+        assert!(lines.contains(&expected), "missing {expected}: {stdout}");
+    }
+}
+
+#[test]
+fn test_list_languages_json() {
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    let output = Command::cargo_bin("rusty-todo-md")
+        .expect("failed to locate rusty-todo-md binary")
+        .current_dir(temp_dir.path())
+        .args(["--list-languages", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert!(stdout.starts_with(r#"{"extensions":["py","rs","#));
+    assert!(stdout.contains(r#""dockerfile""#));
+    assert!(stdout.trim_end().ends_with(
+        r#""filenames":["Dockerfile","CMakeLists.txt","meson.build","meson_options.txt","BUILD","BUILD.bazel","WORKSPACE","WORKSPACE.bazel"]}"#
+    ));
+}
+
+#[test]
+fn test_format_gitlab_prints_code_quality_report() {
+    let (temp_dir, _repo) = init_repo().expect("Failed to initialize test repo");
+    write_fixture(temp_dir.path());
+
+    let output = Command::cargo_bin("rusty-todo-md")
+        .expect("failed to locate rusty-todo-md binary")
+        .current_dir(temp_dir.path())
+        .args([
+            "--format",
+            "gitlab",
+            "--markers",
+            "TODO",
+            "FIXME",
+            "--",
+            "lib.rs",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let issues = report.as_array().expect("report is a JSON array");
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0]["description"], "TODO: split this module");
+    assert_eq!(issues[0]["severity"], "minor");
+    assert_eq!(issues[0]["location"]["lines"]["begin"], 1);
+    assert_eq!(issues[1]["severity"], "major");
+    assert_eq!(issues[1]["location"]["path"], "lib.rs");
+    // The report replaces TODO.md output rather than adding to it.
+    assert!(!temp_dir.path().join("TODO.md").exists());
+}
+
+#[test]
+fn test_report_unsupported_lists_skipped_files() {
+    let (temp_dir, _repo) = init_repo().expect("Failed to initialize test repo");
+    write_fixture(temp_dir.path());
+    fs::write(temp_dir.path().join("data.xyz"), "# TODO: never seen\n").unwrap();
+
+    let output = Command::cargo_bin("rusty-todo-md")
+        .expect("failed to locate rusty-todo-md binary")
+        .current_dir(temp_dir.path())
+        .env("NO_COLOR", "1")
+        .args(["--list", "--report-unsupported", "--", "lib.rs", "data.xyz"])
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(
+        stderr
+            .contains("rusty-todo-md: 1 file(s) skipped, no parser for their type:\n  data.xyz\n"),
+        "unexpected stderr: {stderr:?}"
+    );
+    assert!(!stderr.contains("lib.rs"));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "TODO (1)\n  lib.rs:1: split this module\n"
+    );
+}
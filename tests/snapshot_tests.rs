@@ -390,6 +390,15 @@ pub struct RunOutput {
     pub git_index: Option<String>,
 }
 
+impl RunOutput {
+    /// Read any file (relative to the repo root) the run left behind, for
+    /// scenarios that write more than one output file.
+    pub fn read(&self, rel: &str) -> String {
+        let path = self._temp.path().join(rel);
+        fs::read_to_string(&path).unwrap_or_else(|_| format!("<no {rel} generated>\n"))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Baseline (pre-existing) scenarios
 // ---------------------------------------------------------------------------
@@ -416,6 +425,22 @@ fn mixed_languages() {
     insta::assert_snapshot!(out);
 }
 
+#[test]
+fn threads_single_worker() {
+    // `--threads 1` extracts everything on one thread; more workers must
+    // produce the exact same TODO.md.
+    let out = Scenario::new("mixed_languages")
+        .args(["--threads", "1", "--markers", "TODO", "FIXME", "HACK", "--"])
+        .run()
+        .todo_md;
+    let parallel = Scenario::new("mixed_languages")
+        .args(["--threads", "3", "--markers", "TODO", "FIXME", "HACK", "--"])
+        .run()
+        .todo_md;
+    assert_eq!(out, parallel);
+    insta::assert_snapshot!(out);
+}
+
 #[test]
 fn awkward_positions() {
     let out = Scenario::new("awkward_positions").run().todo_md;
@@ -548,6 +573,63 @@ fn exclude_dir_flag() {
     insta::assert_snapshot!(out.todo_md);
 }
 
+#[test]
+fn warn_threshold_density() {
+    // `--warn-threshold 2` flags busy.rs (3 items) on stderr but not
+    // quiet.rs (1 item); TODO.md is written as usual.
+    let out = Scenario::new("warn_threshold_density")
+        .args(["--warn-threshold", "2", "--markers", "TODO", "FIXME", "--"])
+        .run();
+    insta::assert_snapshot!(out.todo_md);
+    insta::with_settings!({snapshot_suffix => "stderr"}, {
+        let stderr = scrub_stderr(&out.stderr);
+        insta::assert_snapshot!(stderr);
+    });
+}
+
+#[test]
+fn per_dir_partitions() {
+    // `--per-dir 1` writes pkg-a/TODO.md and pkg-b/TODO.md with only their
+    // own entries; the top-level build.rs stays in the root TODO.md.
+    let out = Scenario::new("per_dir_partitions")
+        .args(["--per-dir", "1", "--markers", "TODO", "FIXME", "--"])
+        .run();
+    insta::assert_snapshot!(out.todo_md);
+    insta::with_settings!({snapshot_suffix => "pkg-a"}, {
+        let pkg_a = out.read("pkg-a/TODO.md");
+        insta::assert_snapshot!(pkg_a);
+    });
+    insta::with_settings!({snapshot_suffix => "pkg-b"}, {
+        let pkg_b = out.read("pkg-b/TODO.md");
+        insta::assert_snapshot!(pkg_b);
+    });
+}
+
+#[test]
+fn max_todos_exceeded() {
+    // `--max-todos 2` with three items still writes TODO.md but exits
+    // non-zero with the count.
+    let out = Scenario::new("max_todos_exceeded")
+        .args(["--max-todos", "2", "--markers", "TODO", "FIXME", "--"])
+        .expect_failure()
+        .run();
+    insta::assert_snapshot!(out.todo_md);
+    insta::with_settings!({snapshot_suffix => "stderr"}, {
+        let stderr = scrub_stderr(&out.stderr);
+        insta::assert_snapshot!(stderr);
+    });
+}
+
+#[test]
+fn no_create_without_todos() {
+    // `--no-create` with nothing to report leaves TODO.md absent instead of
+    // creating an empty file.
+    let out = Scenario::new("no_create_without_todos")
+        .args(["--no-create", "--markers", "TODO", "--"])
+        .run();
+    insta::assert_snapshot!(out.todo_md);
+}
+
 // ---------------------------------------------------------------------------
 // Reason-class 5 + 6: language parsers and multi-line block-comment joining
 // ---------------------------------------------------------------------------
@@ -666,6 +748,33 @@ fn regenerate_wipes_todo_md_conflict_markers() {
     insta::assert_snapshot!(out.todo_md);
 }
 
+#[test]
+fn check_up_to_date() {
+    // `--check` passes when the committed TODO.md matches what
+    // `--regenerate` would write, and leaves it alone.
+    let out = Scenario::new("check_up_to_date")
+        .args(["--check", "--markers", "TODO", "FIXME"])
+        .no_file_args()
+        .run();
+    insta::assert_snapshot!(out.todo_md);
+}
+
+#[test]
+fn check_out_of_date() {
+    // A stale TODO.md fails `--check` with a line diff on stderr; the file
+    // itself is not rewritten.
+    let out = Scenario::new("check_out_of_date")
+        .args(["--check", "--markers", "TODO", "FIXME"])
+        .no_file_args()
+        .expect_failure()
+        .run();
+    insta::assert_snapshot!(out.todo_md);
+    insta::with_settings!({snapshot_suffix => "stderr"}, {
+        let stderr = scrub_stderr(&out.stderr);
+        insta::assert_snapshot!(stderr);
+    });
+}
+
 #[test]
 fn source_file_with_conflict_markers_is_skipped() {
     // A source file containing `<<<<<<<` must be skipped (with a stderr
@@ -1,8 +1,10 @@
+use chrono::{DateTime, Utc};
 use git2::IndexAddOption;
 use git2::{Error as GitError, Repository, Signature};
 use log::debug;
 use log::info;
 
+use std::collections::HashMap;
 use std::fs::File;
 
 use std::io::Write;
@@ -78,6 +80,11 @@ pub struct FakeGitOps {
     pub temp_dir: tempfile::TempDir,
     pub staged_files: Vec<std::path::PathBuf>,
     pub tracked_files: Vec<std::path::PathBuf>,
+    /// Commit dates `blame_line` reports per `(file, line)`; lines not
+    /// listed are reported as uncommitted.
+    pub blame_dates: HashMap<(std::path::PathBuf, usize), DateTime<Utc>>,
+    /// Messages `recent_commit_messages` reports, newest first.
+    pub commit_messages: Vec<String>,
 }
 
 #[allow(dead_code)]
@@ -93,8 +100,26 @@ impl FakeGitOps {
             temp_dir,
             staged_files,
             tracked_files,
+            blame_dates: HashMap::new(),
+            commit_messages: Vec::new(),
         }
     }
+
+    pub fn with_blame_date(
+        mut self,
+        file_path: std::path::PathBuf,
+        line_number: usize,
+        date: DateTime<Utc>,
+    ) -> Self {
+        self.blame_dates.insert((file_path, line_number), date);
+        self
+    }
+
+    /// Add a commit older than the ones added so far.
+    pub fn with_commit_message(mut self, message: &str) -> Self {
+        self.commit_messages.push(message.to_string());
+        self
+    }
 }
 
 impl rusty_todo_md::git_utils::GitOpsTrait for FakeGitOps {
@@ -118,4 +143,22 @@ impl rusty_todo_md::git_utils::GitOpsTrait for FakeGitOps {
         index.write()?;
         Ok(())
     }
+    fn blame_line(
+        &self,
+        _repo: &Repository,
+        file_path: &std::path::Path,
+        line_number: usize,
+    ) -> Result<Option<DateTime<Utc>>, GitError> {
+        Ok(self
+            .blame_dates
+            .get(&(file_path.to_path_buf(), line_number))
+            .copied())
+    }
+    fn recent_commit_messages(
+        &self,
+        _repo: &Repository,
+        count: usize,
+    ) -> Result<Vec<String>, GitError> {
+        Ok(self.commit_messages.iter().take(count).cloned().collect())
+    }
 }